@@ -0,0 +1,245 @@
+//!
+//! Desktop capture source list. [`CaptureSourceList`] starts the
+//! [`DesktopCaptureMonitor`](crate::screen::desktop_capture_monitor) and
+//! consumes its events, maintaining an ordered list of [`CaptureSource`]s
+//! that can be used to render a custom screen-share picker.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::capture_sources::CaptureSourceList;
+//!
+//! let sources = CaptureSourceList::new(true, true);
+//! sources.on_change(|sources| {
+//!     for source in sources {
+//!         log_info!("{} {:?} {}", source.order, source.kind, source.name);
+//!     }
+//! });
+//!
+//! // once the user has selected a source
+//! let stream_id = sources.select(&source_id)?;
+//! // use `stream_id` as `chromeMediaSourceId` in `getUserMedia` constraints
+//! ```
+//!
+
+use crate::error::Error;
+use crate::result::Result;
+use crate::screen::desktop_capture_monitor;
+use js_sys::Function;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+/// Type of a [`CaptureSource`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureSourceKind {
+    Screen,
+    Window,
+}
+
+impl From<&str> for CaptureSourceKind {
+    fn from(kind: &str) -> Self {
+        match kind {
+            "screen" => Self::Screen,
+            _ => Self::Window,
+        }
+    }
+}
+
+/// Screen or window that can be captured
+#[derive(Debug, Clone)]
+pub struct CaptureSource {
+    /// Source id, used with [`CaptureSourceList::select`]
+    pub id: String,
+    /// Screen or window title
+    pub name: String,
+    /// Position of the source in the list
+    pub order: usize,
+    pub kind: CaptureSourceKind,
+    /// `true` if the source is the primary screen
+    pub primary: bool,
+    /// PNG encoded thumbnail, available once the monitor
+    /// has produced a `thumbnailchanged` event for the source
+    pub thumbnail: Option<Vec<u8>>,
+}
+
+type ChangeHandler = Box<dyn FnMut(&[CaptureSource])>;
+
+#[derive(Default)]
+struct Inner {
+    sources: Vec<CaptureSource>,
+    on_change: Option<ChangeHandler>,
+}
+
+impl Inner {
+    fn renumber(&mut self) {
+        for (order, source) in self.sources.iter_mut().enumerate() {
+            source.order = order;
+        }
+    }
+
+    fn position(&self, id: &str) -> Option<usize> {
+        self.sources.iter().position(|source| source.id == id)
+    }
+}
+
+/// Apply `f` to the source list and invoke the `on_change` handler.
+/// The handler is temporarily taken out of the state while it executes,
+/// allowing it to access the [`CaptureSourceList`].
+fn update(inner: &Rc<RefCell<Inner>>, f: impl FnOnce(&mut Inner)) {
+    let (handler, sources) = {
+        let mut inner = inner.borrow_mut();
+        f(&mut inner);
+        inner.renumber();
+        (inner.on_change.take(), inner.sources.clone())
+    };
+
+    if let Some(mut handler) = handler {
+        handler(&sources);
+        inner.borrow_mut().on_change.get_or_insert(handler);
+    }
+}
+
+/// Decode a base64 encoded thumbnail
+fn decode_thumbnail(thumbnail: &str) -> Option<Vec<u8>> {
+    let binary = crate::utils::window().atob(thumbnail).ok()?;
+    Some(binary.chars().map(|c| c as u8).collect())
+}
+
+/// Ordered list of desktop capture sources maintained from
+/// the [`DesktopCaptureMonitor`](crate::screen::desktop_capture_monitor) events.
+///
+/// The listeners are removed and the monitor is stopped when the list is dropped.
+pub struct CaptureSourceList {
+    inner: Rc<RefCell<Inner>>,
+    listeners: Vec<(&'static str, Function)>,
+}
+
+impl CaptureSourceList {
+    /// Start monitoring the capture sources, including screens
+    /// and/or windows.
+    pub fn new(include_screens: bool, include_windows: bool) -> Self {
+        let inner = Rc::new(RefCell::new(Inner::default()));
+        let mut listeners = Vec::new();
+
+        let inner_ = inner.clone();
+        let added = Closure::<dyn FnMut(String, String, u32, String, bool)>::new(
+            move |id: String, name: String, order: u32, kind: String, primary: bool| {
+                update(&inner_, |inner| {
+                    let source = CaptureSource {
+                        id,
+                        name,
+                        order: order as usize,
+                        kind: CaptureSourceKind::from(kind.as_str()),
+                        primary,
+                        thumbnail: None,
+                    };
+                    let index = source.order.min(inner.sources.len());
+                    inner.sources.insert(index, source);
+                });
+            },
+        );
+        listeners.push(("added", added.into_js_value().into()));
+
+        let inner_ = inner.clone();
+        let removed = Closure::<dyn FnMut(u32)>::new(move |order: u32| {
+            update(&inner_, |inner| {
+                if (order as usize) < inner.sources.len() {
+                    inner.sources.remove(order as usize);
+                }
+            });
+        });
+        listeners.push(("removed", removed.into_js_value().into()));
+
+        let inner_ = inner.clone();
+        let order_changed = Closure::<dyn FnMut(String, u32, u32)>::new(
+            move |id: String, new_order: u32, _old_order: u32| {
+                update(&inner_, |inner| {
+                    if let Some(index) = inner.position(&id) {
+                        let source = inner.sources.remove(index);
+                        let index = (new_order as usize).min(inner.sources.len());
+                        inner.sources.insert(index, source);
+                    }
+                });
+            },
+        );
+        listeners.push(("orderchanged", order_changed.into_js_value().into()));
+
+        let inner_ = inner.clone();
+        let name_changed =
+            Closure::<dyn FnMut(String, String)>::new(move |id: String, name: String| {
+                update(&inner_, |inner| {
+                    if let Some(index) = inner.position(&id) {
+                        inner.sources[index].name = name;
+                    }
+                });
+            });
+        listeners.push(("namechanged", name_changed.into_js_value().into()));
+
+        let inner_ = inner.clone();
+        let thumbnail_changed =
+            Closure::<dyn FnMut(String, String)>::new(move |id: String, thumbnail: String| {
+                update(&inner_, |inner| {
+                    if let Some(index) = inner.position(&id) {
+                        inner.sources[index].thumbnail = decode_thumbnail(&thumbnail);
+                    }
+                });
+            });
+        listeners.push(("thumbnailchanged", thumbnail_changed.into_js_value().into()));
+
+        for (event, callback) in listeners.iter() {
+            desktop_capture_monitor::on(event, callback);
+        }
+
+        if desktop_capture_monitor::started() {
+            desktop_capture_monitor::stop();
+        }
+        desktop_capture_monitor::start(include_screens, include_windows);
+
+        Self { inner, listeners }
+    }
+
+    /// Get the current list of capture sources, ordered by [`CaptureSource::order`].
+    pub fn sources(&self) -> Vec<CaptureSource> {
+        self.inner.borrow().sources.clone()
+    }
+
+    /// Get the capture source with the given `id`.
+    pub fn get(&self, id: &str) -> Option<CaptureSource> {
+        let inner = self.inner.borrow();
+        inner.position(id).map(|index| inner.sources[index].clone())
+    }
+
+    /// Closure invoked with the updated list of capture sources
+    /// each time a source is added, removed, reordered or updated.
+    pub fn on_change<F>(&self, callback: F)
+    where
+        F: FnMut(&[CaptureSource]) + 'static,
+    {
+        self.inner.borrow_mut().on_change = Some(Box::new(callback));
+    }
+
+    /// Stop monitoring and register the source with the given `id`
+    /// for capture, returning the stream id to be used as
+    /// `chromeMediaSourceId` in `getUserMedia` constraints.
+    pub fn select(&self, id: &str) -> Result<String> {
+        if self.inner.borrow().position(id).is_none() {
+            return Err(Error::String(format!("unknown capture source: {id}")));
+        }
+
+        if desktop_capture_monitor::started() {
+            desktop_capture_monitor::stop();
+        }
+        Ok(desktop_capture_monitor::register_stream(id))
+    }
+}
+
+impl Drop for CaptureSourceList {
+    fn drop(&mut self) {
+        for (event, callback) in self.listeners.drain(..) {
+            desktop_capture_monitor::remove_listener(event, &callback);
+        }
+        if desktop_capture_monitor::started() {
+            desktop_capture_monitor::stop();
+        }
+    }
+}
@@ -0,0 +1,384 @@
+//!
+//! Chrome alarms API, allows scheduling code to run periodically or at a
+//! specified time in the future (`chrome.alarms`). Unlike `setTimeout()`,
+//! alarms are not throttled while the application is in the background.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::alarms::Scheduler;
+//! use std::time::Duration;
+//!
+//! let scheduler = Scheduler::new()?;
+//! scheduler.every("sync", Duration::from_secs(15 * 60), |_alarm| {
+//!     start_sync();
+//! })?;
+//! scheduler.once_at("reminder", js_sys::Date::now() + 60_000.0, |alarm| {
+//!     log_info!("alarm {} fired", alarm.name);
+//! });
+//!
+//! // keep `scheduler` alive to keep receiving alarms
+//! ```
+//!
+
+use crate::chrome::{self, ListenerHandle};
+use crate::error::Error;
+use crate::options::OptionsTrait;
+use crate::result::Result;
+use crate::utils;
+use js_sys::{Array, Function, Object};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace=["chrome", "alarms"], js_name="create")]
+    /// Creates an alarm. Near the time(s) specified by `alarm_info`, the
+    /// `onAlarm` event is fired. If there is another alarm with the same name,
+    /// it will be cancelled and replaced by this alarm.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#method-create)
+    pub fn create(name: &str, alarm_info: &AlarmCreateInfo);
+
+    #[wasm_bindgen(js_namespace=["chrome", "alarms"], js_name="get")]
+    /// Retrieves details about the specified alarm.
+    ///
+    /// The `callback` parameter looks like:
+    /// (alarm: Alarm | undefined) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#method-get)
+    pub fn get_with_callback(name: &str, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "alarms"], js_name="getAll")]
+    /// Gets an array of all the alarms.
+    ///
+    /// The `callback` parameter looks like:
+    /// (alarms: Alarm[]) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#method-getAll)
+    pub fn get_all_with_callback(callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "alarms"], js_name="clear")]
+    /// Clears the alarm with the given name.
+    ///
+    /// The `callback` parameter looks like:
+    /// (was_cleared: bool) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#method-clear)
+    pub fn clear_with_callback(name: &str, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "alarms"], js_name="clear")]
+    fn clear_impl(name: &str);
+
+    #[wasm_bindgen(js_namespace=["chrome", "alarms"], js_name="clearAll")]
+    /// Clears all alarms.
+    ///
+    /// The `callback` parameter looks like:
+    /// (was_cleared: bool) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#method-clearAll)
+    pub fn clear_all_with_callback(callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "alarms", "onAlarm"], js_name="addListener")]
+    /// Fired when an alarm has elapsed.
+    ///
+    /// The `callback` parameter looks like:
+    /// (alarm: Alarm) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#event-onAlarm)
+    pub fn on_alarm(callback: &Function);
+
+    /// Alarm creation options
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#type-AlarmCreateInfo)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type AlarmCreateInfo;
+}
+
+impl OptionsTrait for AlarmCreateInfo {}
+
+impl AlarmCreateInfo {
+    /// Time at which the alarm should fire, in milliseconds past the epoch.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#property-AlarmCreateInfo-when)
+    pub fn when(self, when: f64) -> Self {
+        self.set("when", JsValue::from(when))
+    }
+
+    /// Length of time in minutes after which the `onAlarm` event should fire.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#property-AlarmCreateInfo-delayInMinutes)
+    pub fn delay_in_minutes(self, delay: f64) -> Self {
+        self.set("delayInMinutes", JsValue::from(delay))
+    }
+
+    /// If set, the `onAlarm` event should fire every `period` minutes after
+    /// the initial event specified by `when` or `delayInMinutes`.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#property-AlarmCreateInfo-periodInMinutes)
+    pub fn period_in_minutes(self, period: f64) -> Self {
+        self.set("periodInMinutes", JsValue::from(period))
+    }
+}
+
+/// Alarm details
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#type-Alarm)
+#[derive(Debug, Clone)]
+pub struct Alarm {
+    /// Name of this alarm
+    pub name: String,
+    /// Time at which this alarm was scheduled to fire, in milliseconds past the epoch
+    pub scheduled_time: f64,
+    /// If not `None`, the alarm is repeating and will fire again in `period_in_minutes` minutes
+    pub period_in_minutes: Option<f64>,
+}
+
+impl TryFrom<JsValue> for Alarm {
+    type Error = Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            name: utils::try_get_string_from_prop(&jsv, "name")?,
+            scheduled_time: utils::try_get_f64_from_prop(&jsv, "scheduledTime")?,
+            period_in_minutes: utils::try_get_f64_from_prop(&jsv, "periodInMinutes").ok(),
+        })
+    }
+}
+
+/// Retrieve the alarm with the given `name`.
+/// Returns `None` if the alarm does not exist.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#method-get)
+pub async fn get(name: &str) -> Result<Option<Alarm>> {
+    chrome::api(&["alarms"])?;
+    let alarm = chrome::call(|callback| {
+        get_with_callback(name, callback);
+        Ok(())
+    })
+    .await?;
+    if alarm.is_undefined() || alarm.is_null() {
+        Ok(None)
+    } else {
+        Ok(Some(Alarm::try_from(alarm)?))
+    }
+}
+
+/// Retrieve all alarms.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#method-getAll)
+pub async fn get_all() -> Result<Vec<Alarm>> {
+    chrome::api(&["alarms"])?;
+    let alarms = chrome::call(|callback| {
+        get_all_with_callback(callback);
+        Ok(())
+    })
+    .await?;
+    Array::from(&alarms).iter().map(Alarm::try_from).collect()
+}
+
+/// Clear the alarm with the given `name`.
+/// Returns `true` if the alarm has been cleared.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#method-clear)
+pub async fn clear(name: &str) -> Result<bool> {
+    chrome::api(&["alarms"])?;
+    let cleared = chrome::call(|callback| {
+        clear_with_callback(name, callback);
+        Ok(())
+    })
+    .await?;
+    Ok(cleared.as_bool().unwrap_or(false))
+}
+
+/// Clear all alarms. Returns `true` if any alarm has been cleared.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/alarms/#method-clearAll)
+pub async fn clear_all() -> Result<bool> {
+    chrome::api(&["alarms"])?;
+    let cleared = chrome::call(|callback| {
+        clear_all_with_callback(callback);
+        Ok(())
+    })
+    .await?;
+    Ok(cleared.as_bool().unwrap_or(false))
+}
+
+type AlarmHandler = Box<dyn FnMut(&Alarm)>;
+
+struct Task {
+    handler: AlarmHandler,
+    repeating: bool,
+}
+
+#[derive(Default)]
+struct State {
+    tasks: HashMap<String, Task>,
+    /// Alarm being dispatched and whether its closure cancelled it
+    dispatching: Option<(String, bool)>,
+}
+
+type Tasks = Rc<RefCell<State>>;
+
+/// Invoke the closure registered for `alarm`. The task is temporarily
+/// removed while the closure executes, allowing it to schedule or
+/// cancel alarms (including its own).
+fn dispatch(tasks: &Tasks, alarm: Alarm) {
+    let mut state = tasks.borrow_mut();
+    let Some(mut task) = state.tasks.remove(&alarm.name) else {
+        return;
+    };
+    state.dispatching = Some((alarm.name.clone(), false));
+    drop(state);
+
+    (task.handler)(&alarm);
+
+    let mut state = tasks.borrow_mut();
+    let cancelled = state
+        .dispatching
+        .take()
+        .is_some_and(|(_, cancelled)| cancelled);
+    if task.repeating && !cancelled {
+        state.tasks.entry(alarm.name).or_insert(task);
+    }
+}
+
+/// Minimum period accepted by [`Scheduler::every`], Chrome does not
+/// fire repeating alarms more often than every 30 seconds.
+pub const MIN_PERIOD: Duration = Duration::from_secs(30);
+
+/// Convert an alarm `period` to minutes, rejecting periods
+/// shorter than [`MIN_PERIOD`].
+fn period_in_minutes(period: Duration) -> Result<f64> {
+    if period < MIN_PERIOD {
+        return Err(Error::String(format!(
+            "alarm period must be at least {}s, got {period:?}",
+            MIN_PERIOD.as_secs()
+        )));
+    }
+    Ok(period.as_secs_f64() / 60.0)
+}
+
+struct Inner {
+    tasks: Tasks,
+    _listener: ListenerHandle<dyn FnMut(JsValue)>,
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        for name in self.tasks.borrow().tasks.keys() {
+            clear_impl(name);
+        }
+    }
+}
+
+/// Named alarm scheduler dispatching `onAlarm` events to per-alarm closures.
+///
+/// Cloning a scheduler returns another handle to the same scheduler, so
+/// a clone can be moved into an alarm closure (e.g. to cancel its own
+/// alarm). Alarms scheduled by the scheduler are cleared and the listener
+/// is removed when the last handle is dropped; a handle captured by a
+/// repeating alarm keeps the scheduler alive until that alarm is cancelled.
+#[derive(Clone)]
+pub struct Scheduler {
+    inner: Rc<Inner>,
+}
+
+impl Scheduler {
+    /// Create a scheduler. Returns [`Error::NotAvailable`]
+    /// if `chrome.alarms` is not available.
+    pub fn new() -> Result<Self> {
+        let tasks = Tasks::default();
+        let tasks_ = tasks.clone();
+        let closure =
+            Closure::<dyn FnMut(JsValue)>::new(move |alarm: JsValue| {
+                match Alarm::try_from(alarm) {
+                    Ok(alarm) => dispatch(&tasks_, alarm),
+                    Err(err) => crate::nw::error(&err.to_string()),
+                }
+            });
+        let listener = ListenerHandle::try_new(&["alarms", "onAlarm"], closure)?;
+
+        Ok(Self {
+            inner: Rc::new(Inner {
+                tasks,
+                _listener: listener,
+            }),
+        })
+    }
+
+    fn schedule<F>(&self, name: &str, info: AlarmCreateInfo, repeating: bool, handler: F)
+    where
+        F: FnMut(&Alarm) + 'static,
+    {
+        self.inner.tasks.borrow_mut().tasks.insert(
+            name.to_string(),
+            Task {
+                handler: Box::new(handler),
+                repeating,
+            },
+        );
+        create(name, &info);
+    }
+
+    /// Schedule the alarm `name` to fire once at `when` (in milliseconds
+    /// past the epoch), replacing any alarm with the same name.
+    pub fn once_at<F>(&self, name: &str, when: f64, handler: F)
+    where
+        F: FnMut(&Alarm) + 'static,
+    {
+        self.schedule(name, AlarmCreateInfo::new().when(when), false, handler);
+    }
+
+    /// Schedule the alarm `name` to fire every `period`, starting one
+    /// `period` from now, replacing any alarm with the same name.
+    ///
+    /// Returns an error if `period` is shorter than [`MIN_PERIOD`].
+    pub fn every<F>(&self, name: &str, period: Duration, handler: F) -> Result<()>
+    where
+        F: FnMut(&Alarm) + 'static,
+    {
+        let minutes = period_in_minutes(period)?;
+        let info = AlarmCreateInfo::new()
+            .delay_in_minutes(minutes)
+            .period_in_minutes(minutes);
+        self.schedule(name, info, true, handler);
+        Ok(())
+    }
+
+    /// Returns `true` if the alarm `name` is scheduled by this scheduler.
+    pub fn is_scheduled(&self, name: &str) -> bool {
+        self.inner.tasks.borrow().tasks.contains_key(name)
+    }
+
+    /// Cancel the alarm `name`. Can be called from the alarm's own
+    /// closure using a clone of the scheduler.
+    pub fn cancel(&self, name: &str) {
+        let mut state = self.inner.tasks.borrow_mut();
+        state.tasks.remove(name);
+        if let Some((dispatching, cancelled)) = state.dispatching.as_mut() {
+            if dispatching == name {
+                *cancelled = true;
+            }
+        }
+        clear_impl(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_rejects_short_periods() {
+        assert!(period_in_minutes(Duration::ZERO).is_err());
+        assert!(period_in_minutes(Duration::from_secs(29)).is_err());
+        assert_eq!(period_in_minutes(MIN_PERIOD).unwrap(), 0.5);
+        assert_eq!(
+            period_in_minutes(Duration::from_secs(15 * 60)).unwrap(),
+            15.0
+        );
+    }
+}
@@ -0,0 +1,479 @@
+//!
+//! Chrome context menus API, adds items to the page context menu
+//! (`chrome.contextMenus`). Unlike intercepting the `contextmenu` DOM
+//! event, items can be restricted to [`Context`]s (e.g. selected text,
+//! links or editable fields) and the click handler receives
+//! [`OnClickData`] describing what was right-clicked.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::context_menus::{self, Context, Properties};
+//!
+//! let id = context_menus::create(
+//!     &Properties::new()
+//!         .id("copy-address")
+//!         .title("Copy address")
+//!         .contexts(&[Context::Selection, Context::Editable]),
+//! )
+//! .await?;
+//!
+//! // `id` is typed, so auto-generated integer ids can be passed back
+//! context_menus::update(&id, &Properties::new().title("Copy")).await?;
+//!
+//! // keep the handle alive to keep receiving clicks
+//! let listener = context_menus::on_clicked(|data| {
+//!     if data.menu_item_id == "copy-address" {
+//!         log_info!("selection: {:?}", data.selection_text);
+//!     }
+//! })?;
+//! ```
+//!
+
+use crate::chrome::{self, ListenerHandle};
+use crate::error::Error;
+use crate::options::OptionsTrait;
+use crate::result::Result;
+use crate::utils;
+use js_sys::{Array, Function, Object};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_namespace=["chrome", "contextMenus"], js_name="create")]
+    /// Creates a new context menu item, returning the item id. The `callback`
+    /// is invoked once the item has been created.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#method-create)
+    pub fn create_with_callback(
+        properties: &Properties,
+        callback: &Function,
+    ) -> std::result::Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace=["chrome", "contextMenus"], js_name="update")]
+    /// Updates a previously created context menu item.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#method-update)
+    pub fn update_with_callback(id: &JsValue, properties: &Properties, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "contextMenus"], js_name="remove")]
+    /// Removes a context menu item.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#method-remove)
+    pub fn remove_with_callback(id: &JsValue, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "contextMenus"], js_name="removeAll")]
+    /// Removes all context menu items added by this application.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#method-removeAll)
+    pub fn remove_all_with_callback(callback: &Function);
+
+    /// Context menu item properties
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#method-create)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type Properties;
+}
+
+impl OptionsTrait for Properties {}
+
+/// Context in which a menu item appears
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#type-ContextType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Context {
+    /// All contexts except [`Context::Launcher`]
+    All,
+    Page,
+    Frame,
+    Selection,
+    Link,
+    Editable,
+    Image,
+    Video,
+    Audio,
+    Launcher,
+}
+
+impl std::fmt::Display for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let context = match self {
+            Self::All => "all",
+            Self::Page => "page",
+            Self::Frame => "frame",
+            Self::Selection => "selection",
+            Self::Link => "link",
+            Self::Editable => "editable",
+            Self::Image => "image",
+            Self::Video => "video",
+            Self::Audio => "audio",
+            Self::Launcher => "launcher",
+        };
+        write!(f, "{context}")
+    }
+}
+
+/// Type of menu item
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#type-ItemType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemType {
+    Normal,
+    Checkbox,
+    Radio,
+    Separator,
+}
+
+impl std::fmt::Display for ItemType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let item_type = match self {
+            Self::Normal => "normal",
+            Self::Checkbox => "checkbox",
+            Self::Radio => "radio",
+            Self::Separator => "separator",
+        };
+        write!(f, "{item_type}")
+    }
+}
+
+fn string_array(values: &[&str]) -> Array {
+    values.iter().map(|value| JsValue::from(*value)).collect()
+}
+
+impl Properties {
+    /// Unique id for the item. Required when the item is used with
+    /// [`on_clicked`] to identify the item (not allowed in [`update`]).
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-id)
+    pub fn id(self, id: &str) -> Self {
+        self.set("id", JsValue::from(id))
+    }
+
+    /// Text to display in the item, `%s` is replaced with the selected text.
+    /// Required unless the type is [`ItemType::Separator`].
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-title)
+    pub fn title(self, title: &str) -> Self {
+        self.set("title", JsValue::from(title))
+    }
+
+    /// Text to display in the item using the localized message `key`
+    /// with `substitutions` (see [`i18n::message`](crate::chrome::i18n::message)).
+    pub fn localized_title(self, key: &str, substitutions: &[&str]) -> Self {
+        self.title(&crate::chrome::i18n::message(key, substitutions))
+    }
+
+    /// Type of menu item, defaults to [`ItemType::Normal`].
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-type)
+    pub fn set_type(self, item_type: ItemType) -> Self {
+        self.set("type", JsValue::from(item_type.to_string()))
+    }
+
+    /// List of contexts this menu item will appear in, defaults to [`Context::Page`].
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-contexts)
+    pub fn contexts(self, contexts: &[Context]) -> Self {
+        let contexts = contexts
+            .iter()
+            .map(|context| JsValue::from(context.to_string()))
+            .collect::<Array>();
+        self.set("contexts", contexts.into())
+    }
+
+    /// Initial state of a checkbox or radio item.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-checked)
+    pub fn checked(self, checked: bool) -> Self {
+        self.set("checked", JsValue::from(checked))
+    }
+
+    /// Whether this item is enabled or disabled, defaults to `true`.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-enabled)
+    pub fn enabled(self, enabled: bool) -> Self {
+        self.set("enabled", JsValue::from(enabled))
+    }
+
+    /// Whether the item is visible in the menu, defaults to `true`.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-visible)
+    pub fn visible(self, visible: bool) -> Self {
+        self.set("visible", JsValue::from(visible))
+    }
+
+    /// Id of a parent menu item, making this item a child of a previously added item.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-parentId)
+    pub fn parent_id(self, parent_id: &MenuItemId) -> Self {
+        self.set("parentId", JsValue::from(parent_id))
+    }
+
+    /// Restricts the item to documents whose URL matches one of the given patterns.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-documentUrlPatterns)
+    pub fn document_url_patterns(self, patterns: &[&str]) -> Self {
+        self.set("documentUrlPatterns", string_array(patterns).into())
+    }
+
+    /// Restricts the item to links and media whose URL matches one of the given patterns.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-targetUrlPatterns)
+    pub fn target_url_patterns(self, patterns: &[&str]) -> Self {
+        self.set("targetUrlPatterns", string_array(patterns).into())
+    }
+
+    /// Function called back when the menu item is clicked.
+    ///
+    /// The `callback` parameter looks like:
+    /// (info: OnClickData, tab: Tab) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#property-createProperties-onclick)
+    pub fn onclick(self, callback: &Function) -> Self {
+        self.set("onclick", JsValue::from(callback))
+    }
+}
+
+/// Id of a context menu item. Items created with an explicit
+/// [`Properties::id`] have a string id, otherwise `chrome.contextMenus`
+/// generates an integer id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MenuItemId {
+    Int(i64),
+    String(String),
+}
+
+impl MenuItemId {
+    /// Build an id from the string or number value of a JS id, preferring
+    /// the string. Returns `None` for non-integer numbers.
+    fn from_parts(string: Option<String>, number: Option<f64>) -> Option<Self> {
+        match (string, number) {
+            (Some(string), _) => Some(MenuItemId::String(string)),
+            (None, Some(number)) if number.fract() == 0.0 && number.is_finite() => {
+                Some(MenuItemId::Int(number as i64))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<&JsValue> for MenuItemId {
+    type Error = Error;
+    fn try_from(jsv: &JsValue) -> std::result::Result<Self, Self::Error> {
+        Self::from_parts(jsv.as_string(), jsv.as_f64())
+            .ok_or_else(|| Error::String(format!("invalid context menu item id: {jsv:?}")))
+    }
+}
+
+impl From<&MenuItemId> for JsValue {
+    fn from(id: &MenuItemId) -> Self {
+        match id {
+            MenuItemId::Int(id) => JsValue::from(*id as f64),
+            MenuItemId::String(id) => JsValue::from(id),
+        }
+    }
+}
+
+impl From<i64> for MenuItemId {
+    fn from(id: i64) -> Self {
+        MenuItemId::Int(id)
+    }
+}
+
+impl From<&str> for MenuItemId {
+    fn from(id: &str) -> Self {
+        MenuItemId::String(id.to_string())
+    }
+}
+
+impl From<String> for MenuItemId {
+    fn from(id: String) -> Self {
+        MenuItemId::String(id)
+    }
+}
+
+impl PartialEq<str> for MenuItemId {
+    fn eq(&self, other: &str) -> bool {
+        matches!(self, MenuItemId::String(id) if id == other)
+    }
+}
+
+impl PartialEq<&str> for MenuItemId {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl fmt::Display for MenuItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MenuItemId::Int(id) => write!(f, "{id}"),
+            MenuItemId::String(id) => write!(f, "{id}"),
+        }
+    }
+}
+
+/// Information sent when a context menu item is clicked
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#type-OnClickData)
+#[derive(Debug, Clone)]
+pub struct OnClickData {
+    /// Id of the menu item that was clicked
+    pub menu_item_id: MenuItemId,
+    /// Parent id of the menu item that was clicked, if any
+    pub parent_menu_item_id: Option<MenuItemId>,
+    /// One of `image`, `video` or `audio` if the context menu was
+    /// activated on one of these types of elements
+    pub media_type: Option<String>,
+    /// URL of the link, if the element is a link
+    pub link_url: Option<String>,
+    /// `src` URL of the element, if the element has one
+    pub src_url: Option<String>,
+    /// URL of the page where the menu item was clicked
+    pub page_url: Option<String>,
+    /// URL of the frame of the element where the context menu was clicked, if it was in a frame
+    pub frame_url: Option<String>,
+    /// Selected text, if any
+    pub selection_text: Option<String>,
+    /// `true` if the element is editable (text input, textarea, etc.)
+    pub editable: bool,
+    /// State of a checkbox or radio item before it was clicked
+    pub was_checked: Option<bool>,
+    /// State of a checkbox or radio item after it was clicked
+    pub checked: Option<bool>,
+}
+
+/// Read an id property which can be a string or a number
+fn read_id(jsv: &JsValue, prop: &str) -> Option<MenuItemId> {
+    let id = utils::try_get_js_value(jsv, prop).ok()?;
+    MenuItemId::try_from(&id).ok()
+}
+
+impl TryFrom<JsValue> for OnClickData {
+    type Error = Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let string = |prop: &str| utils::try_get_string_from_prop(&jsv, prop).ok();
+        let boolean = |prop: &str| utils::try_get_bool_from_prop(&jsv, prop).ok();
+
+        Ok(Self {
+            menu_item_id: read_id(&jsv, "menuItemId")
+                .ok_or_else(|| Error::String("OnClickData: missing `menuItemId`".to_string()))?,
+            parent_menu_item_id: read_id(&jsv, "parentMenuItemId"),
+            media_type: string("mediaType"),
+            link_url: string("linkUrl"),
+            src_url: string("srcUrl"),
+            page_url: string("pageUrl"),
+            frame_url: string("frameUrl"),
+            selection_text: string("selectionText"),
+            editable: boolean("editable").unwrap_or(false),
+            was_checked: boolean("wasChecked"),
+            checked: boolean("checked"),
+        })
+    }
+}
+
+/// Create a new context menu item, returning the item id once the
+/// item has been created (e.g. fails for a duplicate id or an unknown
+/// parent id).
+///
+/// Returns [`Error::NotAvailable`] if `chrome.contextMenus` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#method-create)
+pub async fn create(properties: &Properties) -> Result<MenuItemId> {
+    chrome::api(&["contextMenus"])?;
+    let mut id = JsValue::UNDEFINED;
+    chrome::call(|callback| {
+        id = create_with_callback(properties, callback)?;
+        Ok(())
+    })
+    .await?;
+    MenuItemId::try_from(&id)
+}
+
+/// Update a previously created context menu item.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#method-update)
+pub async fn update(id: &MenuItemId, properties: &Properties) -> Result<()> {
+    chrome::api(&["contextMenus"])?;
+    let id = JsValue::from(id);
+    chrome::call(|callback| {
+        update_with_callback(&id, properties, callback);
+        Ok(())
+    })
+    .await?;
+    Ok(())
+}
+
+/// Remove a context menu item.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#method-remove)
+pub async fn remove(id: &MenuItemId) -> Result<()> {
+    chrome::api(&["contextMenus"])?;
+    let id = JsValue::from(id);
+    chrome::call(|callback| {
+        remove_with_callback(&id, callback);
+        Ok(())
+    })
+    .await?;
+    Ok(())
+}
+
+/// Remove all context menu items added by this application.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#method-removeAll)
+pub async fn remove_all() -> Result<()> {
+    chrome::api(&["contextMenus"])?;
+    chrome::call(|callback| {
+        remove_all_with_callback(callback);
+        Ok(())
+    })
+    .await?;
+    Ok(())
+}
+
+/// Listen for context menu item clicks. The listener is removed
+/// when the returned [`ListenerHandle`] is dropped.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/contextMenus/#event-onClicked)
+pub fn on_clicked<F>(mut callback: F) -> Result<ListenerHandle<dyn FnMut(JsValue)>>
+where
+    F: FnMut(OnClickData) + 'static,
+{
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |info: JsValue| {
+        match OnClickData::try_from(info) {
+            Ok(data) => callback(data),
+            Err(err) => crate::nw::error(&err.to_string()),
+        }
+    });
+    ListenerHandle::try_new(&["contextMenus", "onClicked"], closure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_id_stays_integer() {
+        // `chrome.contextMenus.create` returns a number when no id is given
+        let id = MenuItemId::from_parts(None, Some(7.0)).unwrap();
+        assert_eq!(id, MenuItemId::Int(7));
+        assert_ne!(id, MenuItemId::String("7".to_string()));
+        assert_eq!(id.to_string(), "7");
+    }
+
+    #[test]
+    fn string_id_round_trip() {
+        let id = MenuItemId::from_parts(Some("copy".to_string()), None).unwrap();
+        assert_eq!(id, MenuItemId::from("copy"));
+        assert!(id == "copy");
+        assert!(MenuItemId::Int(1) != "1");
+    }
+
+    #[test]
+    fn rejects_invalid_ids() {
+        assert_eq!(MenuItemId::from_parts(None, None), None);
+        assert_eq!(MenuItemId::from_parts(None, Some(1.5)), None);
+        assert_eq!(MenuItemId::from_parts(None, Some(f64::NAN)), None);
+    }
+}
@@ -0,0 +1,586 @@
+//!
+//! Chrome cookies API, allows querying and modifying cookies and being
+//! notified when they change (`chrome.cookies`). The same interface is
+//! available per window as [`Window::cookies`](crate::window::Window::cookies),
+//! giving access to the cookie store of sites embedded in the window.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::cookies::{self, CookieDetails, CookieFilter, SetDetails};
+//!
+//! let session = cookies::get(
+//!     &CookieDetails::new().url("https://example.com").name("session"),
+//! )
+//! .await?;
+//!
+//! let win = nw_sys::window::get();
+//! win.cookies()
+//!     .set(
+//!         &SetDetails::new()
+//!             .url("https://example.com")
+//!             .name("session")
+//!             .value("e3b0c442")
+//!             .http_only(true),
+//!     )
+//!     .await?;
+//!
+//! for cookie in cookies::get_all(&CookieFilter::new().domain("example.com")).await? {
+//!     log_info!("{} = {}", cookie.name, cookie.value);
+//! }
+//!
+//! // keep the handle alive to keep receiving events
+//! let listener = cookies::on_changed(|change| {
+//!     log_info!("{} changed ({:?})", change.cookie.name, change.cause);
+//! })?;
+//! ```
+//!
+
+use crate::chrome::{self, Event, ListenerHandle};
+use crate::error::Error;
+use crate::options::OptionsTrait;
+use crate::result::Result;
+use crate::utils;
+use js_sys::{Array, Function, Object};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Cookies API object, either `chrome.cookies` or `win.cookies`
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    pub type Cookies;
+
+    #[wasm_bindgen(catch, method, js_name = get)]
+    /// Retrieves information about a single cookie.
+    ///
+    /// The `callback` parameter looks like:
+    /// (cookie: Cookie | null) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-get)
+    pub fn get_with_callback(
+        this: &Cookies,
+        details: &CookieDetails,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = getAll)]
+    /// Retrieves all cookies from a single cookie store that match the given information.
+    ///
+    /// The `callback` parameter looks like:
+    /// (cookies: Cookie[]) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    pub fn get_all_with_callback(
+        this: &Cookies,
+        filter: &CookieFilter,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = set)]
+    /// Sets a cookie with the given cookie data; may overwrite equivalent cookies if they exist.
+    ///
+    /// The `callback` parameter looks like:
+    /// (cookie: Cookie | null) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn set_with_callback(
+        this: &Cookies,
+        details: &SetDetails,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = remove)]
+    /// Deletes a cookie by name.
+    ///
+    /// The `callback` parameter looks like:
+    /// (details: CookieDetails | null) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-remove)
+    pub fn remove_with_callback(
+        this: &Cookies,
+        details: &CookieDetails,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+
+    #[wasm_bindgen(catch, method, js_name = getAllCookieStores)]
+    /// Lists all existing cookie stores.
+    ///
+    /// The `callback` parameter looks like:
+    /// (cookie_stores: CookieStore[]) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAllCookieStores)
+    pub fn get_all_cookie_stores_with_callback(
+        this: &Cookies,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+
+    #[wasm_bindgen(method, getter, js_name = onChanged)]
+    /// Fired when a cookie is set or removed.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#event-onChanged)
+    pub fn on_changed_event(this: &Cookies) -> JsValue;
+
+    /// Cookie identification used by [`get`] and [`remove`]
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#type-CookieDetails)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type CookieDetails;
+
+    /// Cookie filter used by [`get_all`]
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type CookieFilter;
+
+    /// Cookie data used by [`set`]
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type SetDetails;
+}
+
+/// Cookie `SameSite` state
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#type-SameSiteStatus)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameSite {
+    /// `SameSite=None`
+    NoRestriction,
+    Lax,
+    Strict,
+    /// Cookie set without a `SameSite` attribute
+    Unspecified,
+}
+
+impl SameSite {
+    fn from_str(same_site: &str) -> Option<Self> {
+        match same_site {
+            "no_restriction" => Some(Self::NoRestriction),
+            "lax" => Some(Self::Lax),
+            "strict" => Some(Self::Strict),
+            "unspecified" => Some(Self::Unspecified),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SameSite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let same_site = match self {
+            Self::NoRestriction => "no_restriction",
+            Self::Lax => "lax",
+            Self::Strict => "strict",
+            Self::Unspecified => "unspecified",
+        };
+        write!(f, "{same_site}")
+    }
+}
+
+impl OptionsTrait for CookieDetails {}
+
+impl CookieDetails {
+    /// The URL with which the cookie is associated.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#property-CookieDetails-url)
+    pub fn url(self, url: &str) -> Self {
+        self.set("url", JsValue::from(url))
+    }
+
+    /// The name of the cookie.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#property-CookieDetails-name)
+    pub fn name(self, name: &str) -> Self {
+        self.set("name", JsValue::from(name))
+    }
+
+    /// The id of the cookie store, defaults to the store of the current context.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#property-CookieDetails-storeId)
+    pub fn store_id(self, store_id: &str) -> Self {
+        self.set("storeId", JsValue::from(store_id))
+    }
+}
+
+impl OptionsTrait for CookieFilter {}
+
+impl CookieFilter {
+    /// Restricts the cookies to those that would match the given URL.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    pub fn url(self, url: &str) -> Self {
+        self.set("url", JsValue::from(url))
+    }
+
+    /// Filters the cookies by name.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    pub fn name(self, name: &str) -> Self {
+        self.set("name", JsValue::from(name))
+    }
+
+    /// Restricts the cookies to those whose domains match or are subdomains of `domain`.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    pub fn domain(self, domain: &str) -> Self {
+        self.set("domain", JsValue::from(domain))
+    }
+
+    /// Restricts the cookies to those whose path exactly matches `path`.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    pub fn path(self, path: &str) -> Self {
+        self.set("path", JsValue::from(path))
+    }
+
+    /// Filters the cookies by their `Secure` property.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    pub fn secure(self, secure: bool) -> Self {
+        self.set("secure", JsValue::from(secure))
+    }
+
+    /// Filters out session vs. persistent cookies.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    pub fn session(self, session: bool) -> Self {
+        self.set("session", JsValue::from(session))
+    }
+
+    /// The cookie store to retrieve cookies from.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    pub fn store_id(self, store_id: &str) -> Self {
+        self.set("storeId", JsValue::from(store_id))
+    }
+}
+
+impl OptionsTrait for SetDetails {}
+
+impl SetDetails {
+    /// The request-URI to associate with the cookie (required).
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn url(self, url: &str) -> Self {
+        self.set("url", JsValue::from(url))
+    }
+
+    /// The name of the cookie, empty by default.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn name(self, name: &str) -> Self {
+        self.set("name", JsValue::from(name))
+    }
+
+    /// The value of the cookie, empty by default.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn value(self, value: &str) -> Self {
+        self.set("value", JsValue::from(value))
+    }
+
+    /// The domain of the cookie. If omitted, the cookie becomes a host-only cookie.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn domain(self, domain: &str) -> Self {
+        self.set("domain", JsValue::from(domain))
+    }
+
+    /// The path of the cookie, defaults to the path portion of the url.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn path(self, path: &str) -> Self {
+        self.set("path", JsValue::from(path))
+    }
+
+    /// Whether the cookie should be marked as `Secure`.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn secure(self, secure: bool) -> Self {
+        self.set("secure", JsValue::from(secure))
+    }
+
+    /// Whether the cookie should be marked as `HttpOnly`.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn http_only(self, http_only: bool) -> Self {
+        self.set("httpOnly", JsValue::from(http_only))
+    }
+
+    /// The cookie's `SameSite` state.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn same_site(self, same_site: SameSite) -> Self {
+        self.set("sameSite", JsValue::from(same_site.to_string()))
+    }
+
+    /// The expiration date of the cookie in seconds since the UNIX epoch.
+    /// If omitted, the cookie becomes a session cookie.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn expiration_date(self, expiration_date: f64) -> Self {
+        self.set("expirationDate", JsValue::from(expiration_date))
+    }
+
+    /// The id of the cookie store in which to set the cookie.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub fn store_id(self, store_id: &str) -> Self {
+        self.set("storeId", JsValue::from(store_id))
+    }
+}
+
+/// Information about an HTTP cookie
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#type-Cookie)
+#[derive(Debug, Clone)]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    /// The domain of the cookie (e.g. "www.google.com", "example.com")
+    pub domain: String,
+    /// `true` if the cookie is a host-only cookie (i.e. a request's
+    /// host must exactly match the domain of the cookie)
+    pub host_only: bool,
+    pub path: String,
+    pub secure: bool,
+    /// `true` if the cookie is marked as `HttpOnly`
+    pub http_only: bool,
+    pub same_site: SameSite,
+    /// `true` if the cookie is a session cookie, as opposed to a persistent cookie
+    pub session: bool,
+    /// Expiration date in seconds since the UNIX epoch, `None` for session cookies
+    pub expiration_date: Option<f64>,
+    /// The id of the cookie store containing this cookie
+    pub store_id: String,
+}
+
+impl TryFrom<JsValue> for Cookie {
+    type Error = Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            name: utils::try_get_string_from_prop(&jsv, "name")?,
+            value: utils::try_get_string_from_prop(&jsv, "value")?,
+            domain: utils::try_get_string_from_prop(&jsv, "domain")?,
+            host_only: utils::try_get_bool_from_prop(&jsv, "hostOnly").unwrap_or(false),
+            path: utils::try_get_string_from_prop(&jsv, "path")?,
+            secure: utils::try_get_bool_from_prop(&jsv, "secure").unwrap_or(false),
+            http_only: utils::try_get_bool_from_prop(&jsv, "httpOnly").unwrap_or(false),
+            same_site: utils::try_get_string_from_prop(&jsv, "sameSite")
+                .ok()
+                .as_deref()
+                .and_then(SameSite::from_str)
+                .unwrap_or(SameSite::Unspecified),
+            session: utils::try_get_bool_from_prop(&jsv, "session").unwrap_or(false),
+            expiration_date: utils::try_get_f64_from_prop(&jsv, "expirationDate").ok(),
+            store_id: utils::try_get_string_from_prop(&jsv, "storeId").unwrap_or_default(),
+        })
+    }
+}
+
+/// A cookie store
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#type-CookieStore)
+#[derive(Debug, Clone)]
+pub struct CookieStore {
+    /// The unique identifier of the cookie store
+    pub id: String,
+    /// Identifiers of all the browser tabs that share this cookie store
+    pub tab_ids: Vec<u32>,
+}
+
+impl TryFrom<JsValue> for CookieStore {
+    type Error = Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let tab_ids = utils::try_get_js_value(&jsv, "tabIds")?;
+        Ok(Self {
+            id: utils::try_get_string_from_prop(&jsv, "id")?,
+            tab_ids: Array::from(&tab_ids)
+                .iter()
+                .filter_map(|id| id.as_f64().map(|id| id as u32))
+                .collect(),
+        })
+    }
+}
+
+/// Reason of a cookie change
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#type-OnChangedCause)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnChangedCause {
+    /// The cookie was inserted or removed via an explicit call
+    Explicit,
+    /// The cookie was automatically removed due to garbage collection
+    Evicted,
+    /// The cookie was automatically removed due to expiry
+    Expired,
+    /// The cookie was overwritten with an already-expired expiration date
+    ExpiredOverwrite,
+    /// The cookie was automatically removed due to a set call that overwrote it
+    Overwrite,
+}
+
+impl OnChangedCause {
+    fn from_str(cause: &str) -> Option<Self> {
+        match cause {
+            "explicit" => Some(Self::Explicit),
+            "evicted" => Some(Self::Evicted),
+            "expired" => Some(Self::Expired),
+            "expired_overwrite" => Some(Self::ExpiredOverwrite),
+            "overwrite" => Some(Self::Overwrite),
+            _ => None,
+        }
+    }
+}
+
+/// Cookie change reported by [`on_changed`]
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#event-onChanged)
+#[derive(Debug, Clone)]
+pub struct CookieChange {
+    /// `true` if the cookie was removed
+    pub removed: bool,
+    /// Information about the cookie that was set or removed
+    pub cookie: Cookie,
+    pub cause: OnChangedCause,
+}
+
+impl TryFrom<JsValue> for CookieChange {
+    type Error = Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let cause = utils::try_get_string_from_prop(&jsv, "cause")?;
+        Ok(Self {
+            removed: utils::try_get_bool_from_prop(&jsv, "removed")?,
+            cookie: Cookie::try_from(utils::try_get_js_value(&jsv, "cookie")?)?,
+            cause: OnChangedCause::from_str(&cause)
+                .ok_or_else(|| Error::String(format!("CookieChange: unknown cause `{cause}`")))?,
+        })
+    }
+}
+
+impl Cookies {
+    /// Get the `chrome.cookies` object. Returns
+    /// [`Error::NotAvailable`] if `chrome.cookies` is not available.
+    pub fn chrome() -> Result<Self> {
+        Ok(chrome::api(&["cookies"])?.unchecked_into())
+    }
+
+    /// Retrieve a single cookie. If more than one cookie of the same name
+    /// exists for the URL, the one with the longest path is returned.
+    /// Returns `None` if the cookie does not exist.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-get)
+    pub async fn get(&self, details: &CookieDetails) -> Result<Option<Cookie>> {
+        let cookie = chrome::call(|callback| self.get_with_callback(details, callback)).await?;
+        if cookie.is_undefined() || cookie.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(Cookie::try_from(cookie)?))
+        }
+    }
+
+    /// Retrieve all cookies matching `filter`.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAll)
+    pub async fn get_all(&self, filter: &CookieFilter) -> Result<Vec<Cookie>> {
+        let cookies = chrome::call(|callback| self.get_all_with_callback(filter, callback)).await?;
+        Array::from(&cookies).iter().map(Cookie::try_from).collect()
+    }
+
+    /// Set a cookie, returning the cookie that has been set.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-set)
+    pub async fn set(&self, details: &SetDetails) -> Result<Cookie> {
+        let cookie = chrome::call(|callback| self.set_with_callback(details, callback)).await?;
+        if cookie.is_undefined() || cookie.is_null() {
+            Err(Error::String(
+                "cookies::set(): unable to set cookie".to_string(),
+            ))
+        } else {
+            Cookie::try_from(cookie)
+        }
+    }
+
+    /// Delete a cookie by name.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-remove)
+    pub async fn remove(&self, details: &CookieDetails) -> Result<()> {
+        chrome::call(|callback| self.remove_with_callback(details, callback)).await?;
+        Ok(())
+    }
+
+    /// List all existing cookie stores.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#method-getAllCookieStores)
+    pub async fn get_all_cookie_stores(&self) -> Result<Vec<CookieStore>> {
+        let stores =
+            chrome::call(|callback| self.get_all_cookie_stores_with_callback(callback)).await?;
+        Array::from(&stores)
+            .iter()
+            .map(CookieStore::try_from)
+            .collect()
+    }
+
+    /// Listen for cookies being set or removed. The listener is removed
+    /// when the returned [`ListenerHandle`] is dropped.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/cookies/#event-onChanged)
+    pub fn on_changed<F>(&self, mut callback: F) -> Result<ListenerHandle<dyn FnMut(JsValue)>>
+    where
+        F: FnMut(CookieChange) + 'static,
+    {
+        let event = self.on_changed_event();
+        if !event.is_object() {
+            return Err(Error::NotAvailable("cookies.onChanged".to_string()));
+        }
+
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |info: JsValue| {
+            match CookieChange::try_from(info) {
+                Ok(change) => callback(change),
+                Err(err) => crate::nw::error(&err.to_string()),
+            }
+        });
+        Ok(ListenerHandle::new(
+            event.unchecked_into::<Event>(),
+            closure,
+        ))
+    }
+}
+
+/// Retrieve a single cookie from `chrome.cookies`, see [`Cookies::get`].
+pub async fn get(details: &CookieDetails) -> Result<Option<Cookie>> {
+    Cookies::chrome()?.get(details).await
+}
+
+/// Retrieve all cookies matching `filter` from `chrome.cookies`, see [`Cookies::get_all`].
+pub async fn get_all(filter: &CookieFilter) -> Result<Vec<Cookie>> {
+    Cookies::chrome()?.get_all(filter).await
+}
+
+/// Set a cookie using `chrome.cookies`, see [`Cookies::set`].
+pub async fn set(details: &SetDetails) -> Result<Cookie> {
+    Cookies::chrome()?.set(details).await
+}
+
+/// Delete a cookie using `chrome.cookies`, see [`Cookies::remove`].
+pub async fn remove(details: &CookieDetails) -> Result<()> {
+    Cookies::chrome()?.remove(details).await
+}
+
+/// List all existing cookie stores, see [`Cookies::get_all_cookie_stores`].
+pub async fn get_all_cookie_stores() -> Result<Vec<CookieStore>> {
+    Cookies::chrome()?.get_all_cookie_stores().await
+}
+
+/// Listen for cookies being set or removed using `chrome.cookies`,
+/// see [`Cookies::on_changed`].
+pub fn on_changed<F>(callback: F) -> Result<ListenerHandle<dyn FnMut(JsValue)>>
+where
+    F: FnMut(CookieChange) + 'static,
+{
+    Cookies::chrome()?.on_changed(callback)
+}
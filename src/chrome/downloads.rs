@@ -0,0 +1,858 @@
+//!
+//! Chrome downloads API, allows initiating, monitoring, manipulating
+//! and searching downloads (`chrome.downloads`). Requires the `downloads`
+//! permission in the application manifest.
+//!
+//! [`DownloadHandle`] tracks a single download, reporting state and
+//! progress changes and resolving once the download is complete.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::downloads::{DownloadHandle, DownloadOptions};
+//!
+//! let handle = DownloadHandle::start(
+//!     &DownloadOptions::new().url("https://example.com/large-file.zip"),
+//! )
+//! .await?;
+//!
+//! handle.on_update(|item| {
+//!     if let Some(progress) = item.progress() {
+//!         log_info!("{}: {:.0}%", item.filename, progress * 100.0);
+//!     }
+//! });
+//!
+//! let item = handle.wait().await?;
+//! log_info!("downloaded {} bytes to {}", item.bytes_received, item.filename);
+//!
+//! // reveal the downloaded file in the file manager
+//! handle.show_in_folder()?;
+//! ```
+//!
+
+use crate::chrome::{self, ListenerHandle};
+use crate::error::Error;
+use crate::options::OptionsTrait;
+use crate::result::Result;
+use crate::utils::{self, Interval};
+use js_sys::{Array, Function, Object, Promise};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace=["chrome", "downloads"], js_name="download")]
+    /// Downloads a URL.
+    ///
+    /// The `callback` parameter looks like:
+    /// (download_id: number) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-download)
+    pub fn download_with_callback(options: &DownloadOptions, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "downloads"], js_name="search")]
+    /// Finds the downloads matching `query`.
+    ///
+    /// The `callback` parameter looks like:
+    /// (results: DownloadItem[]) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-search)
+    pub fn search_with_callback(query: &DownloadQuery, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "downloads"], js_name="pause")]
+    /// Pauses the download.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-pause)
+    pub fn pause_with_callback(download_id: u32, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "downloads"], js_name="resume")]
+    /// Resumes a paused download.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-resume)
+    pub fn resume_with_callback(download_id: u32, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "downloads"], js_name="cancel")]
+    /// Cancels the download.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-cancel)
+    pub fn cancel_with_callback(download_id: u32, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "downloads"], js_name="open")]
+    /// Opens the downloaded file if the download is complete.
+    /// Must be called in response to a user gesture.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-open)
+    pub fn open(download_id: u32);
+
+    #[wasm_bindgen(js_namespace=["chrome", "downloads"], js_name="show")]
+    /// Shows the downloaded file in its folder in the file manager.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-show)
+    pub fn show(download_id: u32);
+
+    #[wasm_bindgen(js_namespace=["chrome", "downloads"], js_name="showDefaultFolder")]
+    /// Shows the default downloads folder in the file manager.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-showDefaultFolder)
+    pub fn show_default_folder();
+
+    /// Download options
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#type-DownloadOptions)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type DownloadOptions;
+
+    /// Download search query
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#type-DownloadQuery)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub type DownloadQuery;
+}
+
+/// Download state
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#type-State)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    InProgress,
+    Interrupted,
+    Complete,
+}
+
+impl State {
+    fn from_str(state: &str) -> Option<Self> {
+        match state {
+            "in_progress" => Some(Self::InProgress),
+            "interrupted" => Some(Self::Interrupted),
+            "complete" => Some(Self::Complete),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match self {
+            Self::InProgress => "in_progress",
+            Self::Interrupted => "interrupted",
+            Self::Complete => "complete",
+        };
+        write!(f, "{state}")
+    }
+}
+
+/// Action taken if the filename already exists
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#type-FilenameConflictAction)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilenameConflictAction {
+    /// Append a counter to the filename to make it unique
+    Uniquify,
+    /// Overwrite the existing file
+    Overwrite,
+    /// Prompt the user with a file chooser dialog
+    Prompt,
+}
+
+impl std::fmt::Display for FilenameConflictAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let action = match self {
+            Self::Uniquify => "uniquify",
+            Self::Overwrite => "overwrite",
+            Self::Prompt => "prompt",
+        };
+        write!(f, "{action}")
+    }
+}
+
+impl OptionsTrait for DownloadOptions {}
+
+impl DownloadOptions {
+    /// The URL to download.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadOptions-url)
+    pub fn url(self, url: &str) -> Self {
+        self.set("url", JsValue::from(url))
+    }
+
+    /// File path relative to the downloads directory.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadOptions-filename)
+    pub fn filename(self, filename: &str) -> Self {
+        self.set("filename", JsValue::from(filename))
+    }
+
+    /// Action to take if `filename` already exists.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadOptions-conflictAction)
+    pub fn conflict_action(self, action: FilenameConflictAction) -> Self {
+        self.set("conflictAction", JsValue::from(action.to_string()))
+    }
+
+    /// Use a file chooser to allow the user to select a filename.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadOptions-saveAs)
+    pub fn save_as(self, save_as: bool) -> Self {
+        self.set("saveAs", JsValue::from(save_as))
+    }
+
+    /// The HTTP method to use (`GET` or `POST`).
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadOptions-method)
+    pub fn method(self, method: &str) -> Self {
+        self.set("method", JsValue::from(method))
+    }
+
+    /// Extra HTTP headers to send with the request.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadOptions-headers)
+    pub fn headers(self, headers: &[(&str, &str)]) -> Self {
+        let headers = headers
+            .iter()
+            .map(|(name, value)| {
+                let header = Object::new();
+                let _ = js_sys::Reflect::set(&header, &"name".into(), &JsValue::from(*name));
+                let _ = js_sys::Reflect::set(&header, &"value".into(), &JsValue::from(*value));
+                JsValue::from(header)
+            })
+            .collect::<Array>();
+        self.set("headers", headers.into())
+    }
+
+    /// Post body.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadOptions-body)
+    pub fn body(self, body: &str) -> Self {
+        self.set("body", JsValue::from(body))
+    }
+}
+
+impl OptionsTrait for DownloadQuery {}
+
+impl DownloadQuery {
+    /// The id of the download to query.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadQuery-id)
+    pub fn id(self, id: u32) -> Self {
+        self.set("id", JsValue::from(id))
+    }
+
+    /// Search terms; results must contain all terms in the filename
+    /// or url, terms beginning with `-` are excluded.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadQuery-query)
+    pub fn query(self, terms: &[&str]) -> Self {
+        let terms = terms
+            .iter()
+            .map(|term| JsValue::from(*term))
+            .collect::<Array>();
+        self.set("query", terms.into())
+    }
+
+    /// The absolute URL the download initiated from, before any redirects.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadQuery-url)
+    pub fn url(self, url: &str) -> Self {
+        self.set("url", JsValue::from(url))
+    }
+
+    /// Limits results to downloads whose filename matches the regular expression.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadQuery-filenameRegex)
+    pub fn filename_regex(self, regex: &str) -> Self {
+        self.set("filenameRegex", JsValue::from(regex))
+    }
+
+    /// Limits results to downloads in the given state.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadQuery-state)
+    pub fn state(self, state: State) -> Self {
+        self.set("state", JsValue::from(state.to_string()))
+    }
+
+    /// Properties used to sort the results, prefix with `-` for descending order
+    /// (for example `["-startTime"]`).
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadQuery-orderBy)
+    pub fn order_by(self, order_by: &[&str]) -> Self {
+        let order_by = order_by
+            .iter()
+            .map(|prop| JsValue::from(*prop))
+            .collect::<Array>();
+        self.set("orderBy", order_by.into())
+    }
+
+    /// Maximum number of results, defaults to 1000. Set to 0 to return all results.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#property-DownloadQuery-limit)
+    pub fn limit(self, limit: u32) -> Self {
+        self.set("limit", JsValue::from(limit))
+    }
+}
+
+/// Download details
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#type-DownloadItem)
+#[derive(Debug, Clone)]
+pub struct DownloadItem {
+    /// Identifier persistent across browser sessions
+    pub id: u32,
+    /// The URL the download was initiated from, before any redirects
+    pub url: String,
+    /// The URL the download is made from, after all redirects
+    pub final_url: String,
+    /// Absolute local path, empty until the filename has been determined
+    pub filename: String,
+    /// MIME type of the file
+    pub mime: String,
+    pub state: State,
+    /// `true` if the download has stopped reading data from the host
+    pub paused: bool,
+    /// `true` if the download is in progress and paused, or interrupted and can resume
+    pub can_resume: bool,
+    /// Reason the download was interrupted
+    pub error: Option<String>,
+    /// Whether the download is considered safe or suspicious
+    pub danger: String,
+    /// Number of bytes received so far, without considering compression
+    pub bytes_received: f64,
+    /// Total number of bytes, `None` if unknown
+    pub total_bytes: Option<f64>,
+    /// Number of bytes of the whole file after decompression, `None` if unknown
+    pub file_size: Option<f64>,
+    /// Time when the download began in ISO 8601 format
+    pub start_time: String,
+    /// Time when the download ended in ISO 8601 format
+    pub end_time: Option<String>,
+    /// Estimated time when the download will complete in ISO 8601 format
+    pub estimated_end_time: Option<String>,
+    /// `false` if the downloaded file has been removed
+    pub exists: bool,
+}
+
+impl DownloadItem {
+    /// Fraction of the download received (`0.0` to `1.0`),
+    /// `None` if the total size is unknown.
+    pub fn progress(&self) -> Option<f64> {
+        self.total_bytes
+            .filter(|total| *total > 0.0)
+            .map(|total| (self.bytes_received / total).min(1.0))
+    }
+
+    /// Returns `true` if the download is complete.
+    pub fn is_complete(&self) -> bool {
+        self.state == State::Complete
+    }
+}
+
+/// Read a size property where `-1` denotes an unknown size
+fn read_size(jsv: &JsValue, prop: &str) -> Option<f64> {
+    utils::try_get_f64_from_prop(jsv, prop)
+        .ok()
+        .filter(|size| *size >= 0.0)
+}
+
+impl TryFrom<JsValue> for DownloadItem {
+    type Error = Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let state = utils::try_get_string_from_prop(&jsv, "state")?;
+        Ok(Self {
+            id: utils::try_get_u64_from_prop(&jsv, "id")? as u32,
+            url: utils::try_get_string_from_prop(&jsv, "url")?,
+            final_url: utils::try_get_string_from_prop(&jsv, "finalUrl").unwrap_or_default(),
+            filename: utils::try_get_string_from_prop(&jsv, "filename").unwrap_or_default(),
+            mime: utils::try_get_string_from_prop(&jsv, "mime").unwrap_or_default(),
+            state: State::from_str(&state)
+                .ok_or_else(|| Error::String(format!("DownloadItem: unknown state `{state}`")))?,
+            paused: utils::try_get_bool_from_prop(&jsv, "paused").unwrap_or(false),
+            can_resume: utils::try_get_bool_from_prop(&jsv, "canResume").unwrap_or(false),
+            error: utils::try_get_string_from_prop(&jsv, "error").ok(),
+            danger: utils::try_get_string_from_prop(&jsv, "danger").unwrap_or_default(),
+            bytes_received: utils::try_get_f64_from_prop(&jsv, "bytesReceived").unwrap_or(0.0),
+            total_bytes: read_size(&jsv, "totalBytes"),
+            file_size: read_size(&jsv, "fileSize"),
+            start_time: utils::try_get_string_from_prop(&jsv, "startTime").unwrap_or_default(),
+            end_time: utils::try_get_string_from_prop(&jsv, "endTime").ok(),
+            estimated_end_time: utils::try_get_string_from_prop(&jsv, "estimatedEndTime").ok(),
+            exists: utils::try_get_bool_from_prop(&jsv, "exists").unwrap_or(true),
+        })
+    }
+}
+
+/// Changes to a download, as reported by [`on_changed`].
+/// Fields that did not change are `None`.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#type-DownloadDelta)
+#[derive(Debug, Clone)]
+pub struct DownloadDelta {
+    /// Id of the download that changed
+    pub id: u32,
+    pub state: Option<State>,
+    pub paused: Option<bool>,
+    pub can_resume: Option<bool>,
+    pub error: Option<String>,
+    pub filename: Option<String>,
+    pub total_bytes: Option<f64>,
+    pub file_size: Option<f64>,
+    pub exists: Option<bool>,
+}
+
+/// Read the `current` value of a `StringDelta`, `BooleanDelta` or `DoubleDelta` property
+fn current(jsv: &JsValue, prop: &str) -> Option<JsValue> {
+    let delta = utils::try_get_js_value(jsv, prop).ok()?;
+    if !delta.is_object() {
+        return None;
+    }
+    utils::try_get_js_value(&delta, "current")
+        .ok()
+        .filter(|value| !value.is_undefined())
+}
+
+impl TryFrom<JsValue> for DownloadDelta {
+    type Error = Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let string = |prop: &str| current(&jsv, prop).and_then(|value| value.as_string());
+        let boolean = |prop: &str| current(&jsv, prop).and_then(|value| value.as_bool());
+        let size = |prop: &str| current(&jsv, prop).and_then(|value| value.as_f64());
+
+        Ok(Self {
+            id: utils::try_get_u64_from_prop(&jsv, "id")? as u32,
+            state: string("state").as_deref().and_then(State::from_str),
+            paused: boolean("paused"),
+            can_resume: boolean("canResume"),
+            error: string("error"),
+            filename: string("filename"),
+            total_bytes: size("totalBytes"),
+            file_size: size("fileSize"),
+            exists: boolean("exists"),
+        })
+    }
+}
+
+/// Filename suggested by an [`on_determining_filename`] listener
+#[derive(Debug, Clone)]
+pub struct FilenameSuggestion {
+    /// File path relative to the downloads directory
+    pub filename: String,
+    /// Action to take if `filename` already exists
+    pub conflict_action: Option<FilenameConflictAction>,
+}
+
+impl From<FilenameSuggestion> for JsValue {
+    fn from(suggestion: FilenameSuggestion) -> Self {
+        let object = Object::new();
+        let _ = js_sys::Reflect::set(
+            &object,
+            &"filename".into(),
+            &JsValue::from(suggestion.filename),
+        );
+        if let Some(action) = suggestion.conflict_action {
+            let _ = js_sys::Reflect::set(
+                &object,
+                &"conflictAction".into(),
+                &JsValue::from(action.to_string()),
+            );
+        }
+        object.into()
+    }
+}
+
+/// Download a URL, returning the id of the new download.
+///
+/// Returns [`Error::NotAvailable`] if `chrome.downloads` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-download)
+pub async fn download(options: &DownloadOptions) -> Result<u32> {
+    chrome::api(&["downloads"])?;
+    let id = chrome::call(|callback| {
+        download_with_callback(options, callback);
+        Ok(())
+    })
+    .await?;
+    id.as_f64()
+        .map(|id| id as u32)
+        .ok_or_else(|| Error::String("downloads::download(): invalid download id".to_string()))
+}
+
+/// Find the downloads matching `query`.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-search)
+pub async fn search(query: &DownloadQuery) -> Result<Vec<DownloadItem>> {
+    chrome::api(&["downloads"])?;
+    let items = chrome::call(|callback| {
+        search_with_callback(query, callback);
+        Ok(())
+    })
+    .await?;
+    Array::from(&items)
+        .iter()
+        .map(DownloadItem::try_from)
+        .collect()
+}
+
+/// Retrieve the download with the given `id`.
+/// Returns `None` if the download does not exist.
+pub async fn get(id: u32) -> Result<Option<DownloadItem>> {
+    Ok(search(&DownloadQuery::new().id(id))
+        .await?
+        .into_iter()
+        .next())
+}
+
+/// Pause the download.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-pause)
+pub async fn pause(id: u32) -> Result<()> {
+    chrome::api(&["downloads"])?;
+    chrome::call(|callback| {
+        pause_with_callback(id, callback);
+        Ok(())
+    })
+    .await?;
+    Ok(())
+}
+
+/// Resume a paused download.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-resume)
+pub async fn resume(id: u32) -> Result<()> {
+    chrome::api(&["downloads"])?;
+    chrome::call(|callback| {
+        resume_with_callback(id, callback);
+        Ok(())
+    })
+    .await?;
+    Ok(())
+}
+
+/// Cancel the download.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-cancel)
+pub async fn cancel(id: u32) -> Result<()> {
+    chrome::api(&["downloads"])?;
+    chrome::call(|callback| {
+        cancel_with_callback(id, callback);
+        Ok(())
+    })
+    .await?;
+    Ok(())
+}
+
+/// Listen for new downloads. The listener is removed
+/// when the returned [`ListenerHandle`] is dropped.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#event-onCreated)
+pub fn on_created<F>(mut callback: F) -> Result<ListenerHandle<dyn FnMut(JsValue)>>
+where
+    F: FnMut(DownloadItem) + 'static,
+{
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |item: JsValue| {
+        match DownloadItem::try_from(item) {
+            Ok(item) => callback(item),
+            Err(err) => crate::nw::error(&err.to_string()),
+        }
+    });
+    ListenerHandle::try_new(&["downloads", "onCreated"], closure)
+}
+
+/// Listen for download changes. Chrome does not report changes
+/// to `bytesReceived`; use [`DownloadHandle`] to track progress.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#event-onChanged)
+pub fn on_changed<F>(mut callback: F) -> Result<ListenerHandle<dyn FnMut(JsValue)>>
+where
+    F: FnMut(DownloadDelta) + 'static,
+{
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |delta: JsValue| {
+        match DownloadDelta::try_from(delta) {
+            Ok(delta) => callback(delta),
+            Err(err) => crate::nw::error(&err.to_string()),
+        }
+    });
+    ListenerHandle::try_new(&["downloads", "onChanged"], closure)
+}
+
+/// Listen for filename determination, allowing the application to override
+/// the target filename of new downloads. Returning `None` keeps the
+/// filename determined by Chrome.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#event-onDeterminingFilename)
+pub fn on_determining_filename<F>(
+    mut callback: F,
+) -> Result<ListenerHandle<dyn FnMut(JsValue, Function)>>
+where
+    F: FnMut(&DownloadItem) -> Option<FilenameSuggestion> + 'static,
+{
+    let closure =
+        Closure::<dyn FnMut(JsValue, Function)>::new(move |item: JsValue, suggest: Function| {
+            let suggestion = match DownloadItem::try_from(item) {
+                Ok(item) => callback(&item),
+                Err(err) => {
+                    crate::nw::error(&err.to_string());
+                    None
+                }
+            };
+            let result = match suggestion {
+                Some(suggestion) => suggest.call1(&JsValue::undefined(), &suggestion.into()),
+                None => suggest.call0(&JsValue::undefined()),
+            };
+            if let Err(err) = result {
+                crate::nw::error(&format!("downloads: {:?}", err));
+            }
+        });
+    ListenerHandle::try_new(&["downloads", "onDeterminingFilename"], closure)
+}
+
+/// Interval at which [`DownloadHandle`] polls the progress of a download
+const PROGRESS_INTERVAL: u32 = 500;
+
+type UpdateHandler = Box<dyn FnMut(&DownloadItem)>;
+
+struct Tracker {
+    id: u32,
+    item: Option<DownloadItem>,
+    handler: Option<UpdateHandler>,
+    resolve: Option<(Function, Function)>,
+    poll: Option<Interval>,
+    /// A query is in flight
+    refreshing: bool,
+    /// A refresh was requested while a query was in flight
+    dirty: bool,
+}
+
+/// Store `item`, notify the update handler and settle the completion
+/// promise once the download has finished. The handler is invoked without
+/// holding the tracker borrowed, allowing it to access the [`DownloadHandle`].
+fn update(tracker: &Rc<RefCell<Tracker>>, item: DownloadItem, raw: JsValue) {
+    let (handler, resolve) = {
+        let mut tracker = tracker.borrow_mut();
+        tracker.item = Some(item.clone());
+        let resolve = if item.state == State::InProgress {
+            None
+        } else {
+            tracker.poll = None;
+            tracker.resolve.take()
+        };
+        (tracker.handler.take(), resolve)
+    };
+
+    if let Some(mut handler) = handler {
+        handler(&item);
+        tracker.borrow_mut().handler.get_or_insert(handler);
+    }
+
+    if let Some((resolve, reject)) = resolve {
+        if item.state == State::Complete {
+            let _ = resolve.call1(&JsValue::undefined(), &raw);
+        } else {
+            let reason = item.error.as_deref().unwrap_or("interrupted");
+            let _ = reject.call1(
+                &JsValue::undefined(),
+                &JsValue::from(format!("download {} failed: {reason}", item.id)),
+            );
+        }
+    }
+}
+
+/// Query the download `id`, logging errors.
+async fn search_item(id: u32) -> Option<(DownloadItem, JsValue)> {
+    let query = DownloadQuery::new().id(id);
+    let items = match chrome::call(|callback| {
+        search_with_callback(&query, callback);
+        Ok(())
+    })
+    .await
+    {
+        Ok(items) => items,
+        Err(err) => {
+            crate::nw::error(&format!("DownloadHandle: {err}"));
+            return None;
+        }
+    };
+
+    let raw = Array::from(&items).iter().next()?;
+    match DownloadItem::try_from(raw.clone()) {
+        Ok(item) => Some((item, raw)),
+        Err(err) => {
+            crate::nw::error(&format!("DownloadHandle: {err}"));
+            None
+        }
+    }
+}
+
+/// Query the download tracked by `tracker` and update it. Only one query
+/// is in flight at a time so that results are applied in order, a refresh
+/// requested meanwhile is performed once the query completes.
+fn refresh(tracker: Weak<RefCell<Tracker>>) {
+    let id = {
+        let Some(tracker) = tracker.upgrade() else {
+            return;
+        };
+        let mut tracker = tracker.borrow_mut();
+        if tracker.refreshing {
+            tracker.dirty = true;
+            return;
+        }
+        tracker.refreshing = true;
+        tracker.dirty = false;
+        tracker.id
+    };
+
+    wasm_bindgen_futures::spawn_local(async move {
+        let result = search_item(id).await;
+
+        let Some(tracker) = tracker.upgrade() else {
+            return;
+        };
+        if let Some((item, raw)) = result {
+            let pending = tracker.borrow().resolve.is_some();
+            if pending {
+                update(&tracker, item, raw);
+            }
+        }
+
+        let dirty = {
+            let mut tracker = tracker.borrow_mut();
+            tracker.refreshing = false;
+            std::mem::take(&mut tracker.dirty)
+        };
+        if dirty {
+            refresh(Rc::downgrade(&tracker));
+        }
+    });
+}
+
+/// Tracks a single download.
+///
+/// The handle polls the download while it is in progress and reports
+/// each update to the [`on_update`](Self::on_update) handler.
+/// [`wait`](Self::wait) resolves once the download is complete. Tracking
+/// stops when the handle is dropped, the download itself is not cancelled.
+pub struct DownloadHandle {
+    id: u32,
+    tracker: Rc<RefCell<Tracker>>,
+    completion: Promise,
+    _listener: ListenerHandle<dyn FnMut(JsValue)>,
+}
+
+impl DownloadHandle {
+    /// Start downloading and track the new download.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/downloads/#method-download)
+    pub async fn start(options: &DownloadOptions) -> Result<Self> {
+        let id = download(options).await?;
+        Self::track(id)
+    }
+
+    /// Track an existing download.
+    pub fn track(id: u32) -> Result<Self> {
+        let tracker = Rc::new(RefCell::new(Tracker {
+            id,
+            item: None,
+            handler: None,
+            resolve: None,
+            poll: None,
+            refreshing: false,
+            dirty: false,
+        }));
+
+        let tracker_ = tracker.clone();
+        let completion = Promise::new(&mut |resolve: Function, reject: Function| {
+            tracker_.borrow_mut().resolve = Some((resolve, reject));
+        });
+
+        let weak = Rc::downgrade(&tracker);
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |delta: JsValue| {
+            let changed = utils::try_get_u64_from_prop(&delta, "id")
+                .is_ok_and(|changed| changed as u32 == id);
+            if changed {
+                refresh(weak.clone());
+            }
+        });
+        let listener = ListenerHandle::try_new(&["downloads", "onChanged"], closure)?;
+
+        let weak = Rc::downgrade(&tracker);
+        let poll = Interval::new(PROGRESS_INTERVAL, move || refresh(weak.clone()))?;
+        tracker.borrow_mut().poll = Some(poll);
+        refresh(Rc::downgrade(&tracker));
+
+        Ok(Self {
+            id,
+            tracker,
+            completion,
+            _listener: listener,
+        })
+    }
+
+    /// Id of the download.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Latest known state of the download, `None` until the first update.
+    pub fn item(&self) -> Option<DownloadItem> {
+        self.tracker.borrow().item.clone()
+    }
+
+    /// Set the closure invoked on each state or progress update.
+    pub fn on_update<F>(&self, handler: F)
+    where
+        F: FnMut(&DownloadItem) + 'static,
+    {
+        self.tracker.borrow_mut().handler = Some(Box::new(handler));
+    }
+
+    /// Wait for the download to complete, returning the final
+    /// [`DownloadItem`]. Returns an error if the download is interrupted.
+    pub async fn wait(&self) -> Result<DownloadItem> {
+        let item = JsFuture::from(self.completion.clone())
+            .await
+            .map_err(|err| match err.as_string() {
+                Some(message) => Error::String(message),
+                None => Error::exception(err),
+            })?;
+        DownloadItem::try_from(item)
+    }
+
+    /// Pause the download.
+    pub async fn pause(&self) -> Result<()> {
+        pause(self.id).await
+    }
+
+    /// Resume the download.
+    pub async fn resume(&self) -> Result<()> {
+        resume(self.id).await
+    }
+
+    /// Cancel the download.
+    pub async fn cancel(&self) -> Result<()> {
+        cancel(self.id).await
+    }
+
+    /// Open the downloaded file. Must be called in response to a user gesture.
+    pub fn open(&self) -> Result<()> {
+        chrome::api(&["downloads"])?;
+        open(self.id);
+        Ok(())
+    }
+
+    /// Reveal the downloaded file in the file manager using
+    /// [`shell::show_item_in_folder`](crate::shell::show_item_in_folder).
+    /// Returns an error if the download is not complete.
+    pub fn show_in_folder(&self) -> Result<()> {
+        let tracker = self.tracker.borrow();
+        match tracker.item.as_ref() {
+            Some(item) if item.is_complete() && !item.filename.is_empty() => {
+                crate::shell::show_item_in_folder(&item.filename);
+                Ok(())
+            }
+            _ => Err(Error::String(format!(
+                "download {} is not complete",
+                self.id
+            ))),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//!
+//! Chrome i18n API, provides localized strings from the application's
+//! `_locales/<locale>/messages.json` files (`chrome.i18n`).
+//!
+//! Native UI options accept message keys via `localized_*` builder methods
+//! (for example [`menu_item::Options::localized_label`](crate::menu_item::Options::localized_label),
+//! [`tray::Options::localized_tooltip`](crate::tray::Options::localized_tooltip) and
+//! [`notifications::Options::localized_message`](crate::notifications::Options::localized_message)).
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::i18n;
+//!
+//! // "greeting": { "message": "Hello, $1!" }
+//! let greeting = i18n::message("greeting", &["Alice"]);
+//!
+//! let item = nw_sys::MenuItem::new(
+//!     &nw_sys::menu_item::Options::new().localized_label("menu_quit", &[]),
+//! );
+//!
+//! let languages = i18n::accept_languages().await?;
+//! ```
+//!
+
+use crate::chrome;
+use crate::result::Result;
+use js_sys::{Array, Function};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace=["chrome", "i18n"], js_name="getMessage")]
+    /// Gets the localized string for the specified message. If the message is
+    /// missing, this method returns an empty string.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/i18n/#method-getMessage)
+    pub fn get_message(message_name: &str) -> String;
+
+    #[wasm_bindgen(js_namespace=["chrome", "i18n"], js_name="getMessage")]
+    /// Gets the localized string for the specified message, replacing
+    /// the `$1`..`$9` placeholders with `substitutions`.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/i18n/#method-getMessage)
+    pub fn get_message_with_substitutions(message_name: &str, substitutions: &Array) -> String;
+
+    #[wasm_bindgen(js_namespace=["chrome", "i18n"], js_name="getUILanguage")]
+    /// Gets the browser UI language (e.g. `en-US`).
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/i18n/#method-getUILanguage)
+    pub fn get_ui_language() -> String;
+
+    #[wasm_bindgen(js_namespace=["chrome", "i18n"], js_name="getAcceptLanguages")]
+    /// Gets the accept-languages of the browser.
+    ///
+    /// The `callback` parameter looks like:
+    /// (languages: String[]) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/i18n/#method-getAcceptLanguages)
+    pub fn get_accept_languages_with_callback(callback: &Function);
+}
+
+/// Returns `true` if the `chrome.i18n` API is available.
+pub fn is_available() -> bool {
+    chrome::is_available(&["i18n"])
+}
+
+/// Get the localized message `key`, replacing the `$1`..`$9` placeholders
+/// with `substitutions`. Returns `key` if the message is missing or if
+/// `chrome.i18n` is not available, so untranslated keys remain visible.
+pub fn message(key: &str, substitutions: &[&str]) -> String {
+    if !is_available() {
+        return key.to_string();
+    }
+
+    let message = if substitutions.is_empty() {
+        get_message(key)
+    } else {
+        let substitutions = substitutions
+            .iter()
+            .map(|substitution| JsValue::from(*substitution))
+            .collect::<Array>();
+        get_message_with_substitutions(key, &substitutions)
+    };
+
+    if message.is_empty() {
+        key.to_string()
+    } else {
+        message
+    }
+}
+
+/// Get the browser UI language (e.g. `en-US`). Returns
+/// [`Error::NotAvailable`](crate::error::Error::NotAvailable)
+/// if `chrome.i18n` is not available.
+pub fn ui_language() -> Result<String> {
+    chrome::api(&["i18n"])?;
+    Ok(get_ui_language())
+}
+
+/// Get the accept-languages of the browser, in order of preference.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/i18n/#method-getAcceptLanguages)
+pub async fn accept_languages() -> Result<Vec<String>> {
+    chrome::api(&["i18n"])?;
+    let languages = chrome::call(|callback| {
+        get_accept_languages_with_callback(callback);
+        Ok(())
+    })
+    .await?;
+    Ok(Array::from(&languages)
+        .iter()
+        .filter_map(|language| language.as_string())
+        .collect())
+}
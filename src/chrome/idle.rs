@@ -0,0 +1,142 @@
+//!
+//! Chrome idle API, allows applications to detect when the machine's
+//! idle state changes (`chrome.idle`).
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::idle::{self, IdleState, IdleWatcher};
+//!
+//! let state = idle::query_state(60).await?;
+//!
+//! // lock the UI after 5 minutes of inactivity
+//! let watcher = IdleWatcher::new(300, |state| {
+//!     if state != IdleState::Active {
+//!         lock_ui();
+//!     }
+//! })?;
+//! ```
+//!
+
+use crate::chrome::{self, ListenerHandle};
+use crate::error::Error;
+use crate::result::Result;
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace=["chrome", "idle"], js_name="queryState")]
+    /// Returns "locked" if the system is locked, "idle" if the user has not
+    /// generated any input for a specified number of seconds, or "active" otherwise.
+    ///
+    /// The `callback` parameter looks like:
+    /// (state: String) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/idle/#method-queryState)
+    pub fn query_state_with_callback(detection_interval_in_seconds: u32, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["chrome", "idle"], js_name="setDetectionInterval")]
+    /// Sets the interval, in seconds, used to determine when the system is in an
+    /// idle state for `onStateChanged` events. The default interval is 60 seconds.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/idle/#method-setDetectionInterval)
+    pub fn set_detection_interval(interval_in_seconds: u32);
+
+    #[wasm_bindgen(js_namespace=["chrome", "idle", "onStateChanged"], js_name="addListener")]
+    /// Fired when the system changes to an active, idle or locked state.
+    ///
+    /// The `callback` parameter looks like:
+    /// (state: String) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/idle/#event-onStateChanged)
+    pub fn on_state_changed(callback: &Function);
+}
+
+/// Minimum detection interval in seconds accepted by `chrome.idle`
+pub const MIN_DETECTION_INTERVAL: u32 = 15;
+
+/// System idle state
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/idle/#type-IdleState)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleState {
+    Active,
+    Idle,
+    Locked,
+}
+
+impl std::fmt::Display for IdleState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Active => write!(f, "active"),
+            Self::Idle => write!(f, "idle"),
+            Self::Locked => write!(f, "locked"),
+        }
+    }
+}
+
+impl TryFrom<JsValue> for IdleState {
+    type Error = Error;
+    fn try_from(value: JsValue) -> Result<Self> {
+        match value.as_string().as_deref() {
+            Some("active") => Ok(Self::Active),
+            Some("idle") => Ok(Self::Idle),
+            Some("locked") => Ok(Self::Locked),
+            _ => Err(Error::String(format!("invalid idle state: {value:?}"))),
+        }
+    }
+}
+
+/// Query the system idle state. The system is considered idle if the user
+/// has not generated any input for `detection_interval` seconds (at least 15).
+///
+/// Returns [`Error::NotAvailable`] if `chrome.idle` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/idle/#method-queryState)
+pub async fn query_state(detection_interval: u32) -> Result<IdleState> {
+    chrome::api(&["idle"])?;
+    let state = chrome::call(|callback| {
+        query_state_with_callback(detection_interval.max(MIN_DETECTION_INTERVAL), callback);
+        Ok(())
+    })
+    .await?;
+    IdleState::try_from(state)
+}
+
+/// Idle state watcher invoking a closure on idle state transitions.
+///
+/// The listener is removed when the watcher is dropped.
+pub struct IdleWatcher {
+    _listener: ListenerHandle<dyn FnMut(JsValue)>,
+}
+
+impl IdleWatcher {
+    /// Set the detection interval to `detection_interval` seconds (at least 15)
+    /// and invoke `callback` each time the system idle state changes.
+    ///
+    /// Note that the detection interval is global to the application.
+    ///
+    /// Returns [`Error::NotAvailable`] if `chrome.idle` is not available.
+    pub fn new<F>(detection_interval: u32, mut callback: F) -> Result<Self>
+    where
+        F: FnMut(IdleState) + 'static,
+    {
+        chrome::api(&["idle"])?;
+        set_detection_interval(detection_interval.max(MIN_DETECTION_INTERVAL));
+
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |state: JsValue| {
+            match IdleState::try_from(state) {
+                Ok(state) => callback(state),
+                Err(err) => crate::nw::error(&err.to_string()),
+            }
+        });
+        let listener = ListenerHandle::try_new(&["idle", "onStateChanged"], closure)?;
+
+        Ok(Self {
+            _listener: listener,
+        })
+    }
+
+    /// Stop watching the idle state.
+    pub fn stop(self) {}
+}
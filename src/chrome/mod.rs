@@ -1 +1,162 @@
+//!
+//! Bindings for the Chrome extension APIs (`chrome.*`) available to NWJS applications.
+//!
+
+use crate::error::Error;
+use crate::result::Result;
+use js_sys::{Function, Object, Promise};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+pub mod alarms;
+pub mod context_menus;
+pub mod cookies;
+pub mod downloads;
+pub mod i18n;
+pub mod idle;
 pub mod notifications;
+pub mod power;
+pub mod storage;
+pub mod system;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Chrome API event object such as `chrome.notifications.onClosed`
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/events/#type-Event)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    pub type Event;
+
+    #[wasm_bindgen(method, js_name = addListener)]
+    /// Registers an event listener callback to an event.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/events/#type-Event)
+    pub fn add_listener(this: &Event, callback: &Function);
+
+    #[wasm_bindgen(method, js_name = removeListener)]
+    /// Deregisters an event listener callback from an event.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/events/#type-Event)
+    pub fn remove_listener(this: &Event, callback: &Function);
+
+    #[wasm_bindgen(method, js_name = hasListener)]
+    /// Returns `true` if `callback` is registered to the event.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/events/#type-Event)
+    pub fn has_listener(this: &Event, callback: &Function) -> bool;
+}
+
+/// Resolve the `chrome.*` object at `path` (for example `["notifications"]`
+/// for `chrome.notifications`), returning [`Error::NotAvailable`] if the API
+/// is not present (e.g. when running in a regular browser).
+pub fn api(path: &[&str]) -> Result<Object> {
+    let name = || format!("chrome.{}", path.join("."));
+    let mut target = js_sys::Reflect::get(&crate::global::global_this(), &JsValue::from("chrome"))
+        .map_err(|_| Error::NotAvailable(name()))?;
+    for prop in path {
+        if target.is_undefined() || target.is_null() {
+            break;
+        }
+        target = js_sys::Reflect::get(&target, &JsValue::from(*prop))
+            .map_err(|_| Error::NotAvailable(name()))?;
+    }
+
+    target
+        .dyn_into::<Object>()
+        .map_err(|_| Error::NotAvailable(name()))
+}
+
+/// Returns `true` if the `chrome.*` object at `path` is available.
+pub fn is_available(path: &[&str]) -> bool {
+    api(path).is_ok()
+}
+
+/// Resolve the `chrome.*` event object at `path`
+/// (for example `["notifications", "onClosed"]`).
+pub fn event(path: &[&str]) -> Result<Event> {
+    Ok(api(path)?.unchecked_into())
+}
+
+/// Event listener registered to a Chrome API [`Event`].
+/// The listener is removed when the handle is dropped.
+pub struct ListenerHandle<T: ?Sized> {
+    event: Event,
+    closure: Closure<T>,
+}
+
+impl<T: ?Sized> ListenerHandle<T> {
+    /// Register `closure` as a listener of `event`.
+    pub fn new(event: Event, closure: Closure<T>) -> Self {
+        event.add_listener(closure.as_ref().unchecked_ref());
+        Self { event, closure }
+    }
+
+    /// Register `closure` as a listener of the event at `path`.
+    pub fn try_new(path: &[&str], closure: Closure<T>) -> Result<Self> {
+        Ok(Self::new(event(path)?, closure))
+    }
+
+    /// Remove the listener.
+    pub fn remove(self) {}
+}
+
+impl<T: ?Sized> Drop for ListenerHandle<T> {
+    fn drop(&mut self) {
+        self.event
+            .remove_listener(self.closure.as_ref().unchecked_ref());
+    }
+}
+
+/// Get `chrome.runtime.lastError`, which is set if the last
+/// API call failed. Only valid within API callbacks.
+fn last_error() -> Option<Error> {
+    let runtime = api(&["runtime"]).ok()?;
+    let error = js_sys::Reflect::get(&runtime, &JsValue::from("lastError")).ok()?;
+    if error.is_undefined() || error.is_null() {
+        return None;
+    }
+
+    let message = js_sys::Reflect::get(&error, &JsValue::from("message"))
+        .ok()
+        .and_then(|message| message.as_string())
+        .unwrap_or_else(|| format!("{:?}", error));
+    Some(Error::String(message))
+}
+
+/// Invoke a callback-based Chrome API call and await the value passed to the
+/// callback. `f` receives the callback function to pass to the API call.
+/// Exceptions thrown by `f` and `chrome.runtime.lastError` are returned as errors.
+pub(crate) async fn call<F>(f: F) -> Result<JsValue>
+where
+    F: FnOnce(&Function) -> std::result::Result<(), JsValue>,
+{
+    let mut f = Some(f);
+    let mut error = None;
+    let last_error_ = Rc::new(RefCell::new(None));
+    let promise = Promise::new(&mut |resolve: Function, _reject: Function| {
+        let last_error_ = last_error_.clone();
+        let callback = Closure::once_into_js(move |value: JsValue| {
+            *last_error_.borrow_mut() = last_error();
+            let _ = resolve.call1(&JsValue::undefined(), &value);
+        });
+        if let Some(f) = f.take() {
+            if let Err(err) = f(callback.unchecked_ref()) {
+                error = Some(err);
+            }
+        }
+    });
+
+    if let Some(err) = error {
+        return Err(Error::exception(err));
+    }
+
+    let value = JsFuture::from(promise).await.map_err(Error::exception)?;
+    match last_error_.take() {
+        Some(err) => Err(err),
+        None => Ok(value),
+    }
+}
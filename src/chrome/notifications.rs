@@ -13,7 +13,7 @@
 //!     .message("Message Text")
 //!     .context_message("Context Message");
 //!
-//! notifications::create(None, &options, None);
+//! notifications::create(None, &options, None)?;
 //!
 //! // Create notification with buttons
 //! let button1 = notifications::Button::new()
@@ -31,7 +31,7 @@
 //!     .message("Message Text")
 //!     .buttons(vec![button1, button2]);
 //!
-//! notifications::create(None, &options, None);
+//! notifications::create(None, &options, None)?;
 //!
 //! // Create image notification
 //! let options = notifications::Options::new()
@@ -41,7 +41,7 @@
 //!     .message("Message Text")
 //!     .image_url("/resources/setup/document.png");
 //!
-//! notifications::create(None, &options, None);
+//! notifications::create(None, &options, None)?;
 //!
 //! // Create notification with items
 //! let item1 = notifications::Item::new()
@@ -58,7 +58,7 @@
 //!     .message("Message Text")
 //!     .items(vec![item1, item2]);
 //!
-//! notifications::create(None, &options, None);
+//! notifications::create(None, &options, None)?;
 //!
 //! // Create notification with progress
 //! let options = notifications::Options::new()
@@ -67,14 +67,21 @@
 //!     .set_type(notifications::TemplateType::Progress)
 //!     .message("Mesage text")
 //!     .progress(50);
-//! notifications::create(None, &options, None);
+//! notifications::create(None, &options, None)?;
 //!  
 //! ```
 //!
 
+use crate::chrome::{self, i18n, ListenerHandle};
+use crate::error::Error;
 use crate::options::OptionsTrait;
+use crate::result::Result;
 use js_sys::{Array, Function, Object};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 
 #[wasm_bindgen]
 extern "C" {
@@ -99,9 +106,12 @@ extern "C" {
     #[wasm_bindgen(js_namespace=["chrome", "notifications"], js_name="getAll")]
     /// Retrieves all the notifications of this app or extension.
     ///
+    /// The `callback` parameter looks like:
+    /// (notifications: Object) => (), where the object keys are the notification ids.
+    ///
     /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/notifications/#method-getAll)
     ///
-    pub fn get_all(callback: &Function);
+    pub fn get_all_with_callback(callback: &Function);
 
     #[wasm_bindgen(js_namespace=["chrome", "notifications"], js_name="getPermissionLevel")]
     /// Retrieves whether the user has enabled notifications from this app or extension.
@@ -218,6 +228,11 @@ impl Button {
     pub fn title(self, title: &str) -> Self {
         self.set("title", JsValue::from(title))
     }
+
+    /// Button text using the localized message `key` with `substitutions`.
+    pub fn localized_title(self, key: &str, substitutions: &[&str]) -> Self {
+        self.title(&i18n::message(key, substitutions))
+    }
 }
 
 impl Item {
@@ -268,6 +283,12 @@ impl Options {
         self.set("contextMessage", JsValue::from(context_message))
     }
 
+    /// Alternate notification content using the localized message
+    /// `key` with `substitutions` (see [`i18n::message`]).
+    pub fn localized_context_message(self, key: &str, substitutions: &[&str]) -> Self {
+        self.context_message(&i18n::message(key, substitutions))
+    }
+
     /// A timestamp associated with the notification, in milliseconds past the epoch (e.g. Date.now() + n).
     ///
     /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/notifications/#property-NotificationOptions-eventTime)
@@ -315,6 +336,12 @@ impl Options {
         self.set("message", JsValue::from(message))
     }
 
+    /// Main notification content using the localized message
+    /// `key` with `substitutions` (see [`i18n::message`]).
+    pub fn localized_message(self, key: &str, substitutions: &[&str]) -> Self {
+        self.message(&i18n::message(key, substitutions))
+    }
+
     /// Priority ranges from -2 to 2. -2 is lowest priority. 2 is highest.
     /// Zero is default.
     /// On platforms that don't support a notification center
@@ -357,6 +384,12 @@ impl Options {
         self.set("title", JsValue::from(title))
     }
 
+    /// Title of the notification using the localized message
+    /// `key` with `substitutions` (see [`i18n::message`]).
+    pub fn localized_title(self, key: &str, substitutions: &[&str]) -> Self {
+        self.title(&i18n::message(key, substitutions))
+    }
+
     /// Which type of notification to display.
     ///
     /// Required for [notifications.create](self::create) method.
@@ -367,11 +400,115 @@ impl Options {
     }
 }
 
+/// Maximum number of notification action buttons
+const MAX_BUTTONS: u32 = 2;
+/// Maximum notification progress value
+const MAX_PROGRESS: f64 = 100.0;
+
+/// Value of an option field, decoded for validation
+#[derive(Debug, Clone, PartialEq)]
+enum Field {
+    Number(f64),
+    String(String),
+    /// Array with the given length
+    Array(u32),
+    Other,
+}
+
+/// Check the template-specific limits of the option fields returned
+/// by `field` (see [`Options::validate`]).
+fn check_fields(field: &impl Fn(&str) -> Option<Field>) -> Result<()> {
+    if let Some(Field::Array(buttons)) = field("buttons") {
+        if buttons > MAX_BUTTONS {
+            return Err(Error::String(format!(
+                "notification supports at most {MAX_BUTTONS} buttons, {buttons} supplied"
+            )));
+        }
+    }
+
+    match field("progress") {
+        None => {}
+        Some(Field::Number(progress)) if (0.0..=MAX_PROGRESS).contains(&progress) => {}
+        Some(progress) => {
+            return Err(Error::String(format!(
+                "notification progress must be in the range 0 to 100, got {progress:?}"
+            )))
+        }
+    }
+
+    match field("type") {
+        Some(Field::String(template_type)) if template_type == "list" => match field("items") {
+            Some(Field::Array(items)) if items > 0 => Ok(()),
+            _ => Err(Error::String(
+                "`list` notification requires at least one item".to_string(),
+            )),
+        },
+        Some(Field::String(template_type))
+            if template_type == "image" && field("imageUrl").is_none() =>
+        {
+            Err(Error::String(
+                "`image` notification requires an `imageUrl`".to_string(),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check the fields required by [`create`] followed by [`check_fields`].
+fn check_create_fields(field: &impl Fn(&str) -> Option<Field>) -> Result<()> {
+    for name in ["type", "iconUrl", "title", "message"] {
+        if field(name).is_none() {
+            return Err(Error::String(format!(
+                "notification `{name}` is required to create a notification"
+            )));
+        }
+    }
+    check_fields(field)
+}
+
+impl Options {
+    fn field(&self, name: &str) -> Option<Field> {
+        let value = js_sys::Reflect::get(self, &JsValue::from(name))
+            .ok()
+            .filter(|value| !value.is_undefined() && !value.is_null())?;
+        Some(if let Some(number) = value.as_f64() {
+            Field::Number(number)
+        } else if let Some(string) = value.as_string() {
+            Field::String(string)
+        } else if let Some(array) = value.dyn_ref::<Array>() {
+            Field::Array(array.length())
+        } else {
+            Field::Other
+        })
+    }
+
+    /// Check the template-specific required fields and limits:
+    /// `list` notifications require `items`, `image` notifications require
+    /// an `imageUrl`, `progress` must be in the range 0 to 100 and at most
+    /// two `buttons` can be supplied. Fields that are not set are not checked,
+    /// allowing partial options to be used with [`update`].
+    pub fn validate(&self) -> Result<()> {
+        check_fields(&|name| self.field(name))
+    }
+
+    /// Check the fields required by [`create`] (`type`, `iconUrl`,
+    /// `title` and `message`) followed by [`Options::validate`].
+    fn validate_create(&self) -> Result<()> {
+        check_create_fields(&|name| self.field(name))
+    }
+}
+
 /// Creates and displays a notification.
 ///
+/// The `options` are validated before the notification is created,
+/// see [`Options::validate`]. Returns [`Error::NotAvailable`] if
+/// `chrome.notifications` is not available.
+///
 /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/notifications/#method-create)
 ///
-pub fn create(id: Option<String>, options: &Options, callback: Option<&Function>) {
+pub fn create(id: Option<String>, options: &Options, callback: Option<&Function>) -> Result<()> {
+    chrome::api(&["notifications"])?;
+    options.validate_create()?;
     match (id, callback) {
         (Some(id), Some(callback)) => {
             create_with_id_and_callback_impl(&id, options, callback);
@@ -386,28 +523,697 @@ pub fn create(id: Option<String>, options: &Options, callback: Option<&Function>
             create_impl(options);
         }
     }
+    Ok(())
 }
 
-/// Clears the specified notification.
+/// Clears the specified notification, releasing the closures registered
+/// for it by [`Notification`] (its `on_close` closure is not invoked).
+/// Returns [`Error::NotAvailable`] if `chrome.notifications` is not available.
 ///
 /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/notifications/#method-clear)
 ///
-pub fn clear(id: &str, callback: Option<&Function>) {
+pub fn clear(id: &str, callback: Option<&Function>) -> Result<()> {
+    chrome::api(&["notifications"])?;
+    unregister(id);
     if let Some(callback) = callback {
         clear_with_callback_impl(id, callback);
     } else {
         clear_impl(id);
     }
+    Ok(())
 }
 
 /// Updates an existing notification.
 ///
+/// The `options` are validated before the notification is updated,
+/// see [`Options::validate`]. Returns [`Error::NotAvailable`] if
+/// `chrome.notifications` is not available.
+///
 /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/notifications/#method-update)
 ///
-pub fn update(id: &str, options: &Options, callback: Option<&Function>) {
+pub fn update(id: &str, options: &Options, callback: Option<&Function>) -> Result<()> {
+    chrome::api(&["notifications"])?;
+    options.validate()?;
     if let Some(callback) = callback {
         update_with_callback_impl(id, options, callback);
     } else {
         update_impl(id, options);
     }
+    Ok(())
+}
+
+/// Notification permission level
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/notifications/#type-PermissionLevel)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionLevel {
+    /// The user has elected to show notifications from the app or extension.
+    Granted,
+    /// The user has elected not to show notifications from the app or extension.
+    Denied,
+}
+
+impl std::fmt::Display for PermissionLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Granted => write!(f, "granted"),
+            Self::Denied => write!(f, "denied"),
+        }
+    }
+}
+
+impl TryFrom<JsValue> for PermissionLevel {
+    type Error = Error;
+    fn try_from(value: JsValue) -> Result<Self> {
+        match value.as_string().as_deref() {
+            Some("granted") => Ok(Self::Granted),
+            Some("denied") => Ok(Self::Denied),
+            _ => Err(Error::String(format!(
+                "invalid notification permission level: {value:?}"
+            ))),
+        }
+    }
+}
+
+/// Returns `true` if the `chrome.notifications` API is available.
+pub fn is_available() -> bool {
+    chrome::is_available(&["notifications"])
+}
+
+/// Retrieves whether the user has enabled notifications from this app or extension.
+///
+/// Returns [`Error::NotAvailable`] if `chrome.notifications` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/notifications/#method-getPermissionLevel)
+///
+pub async fn permission_level() -> Result<PermissionLevel> {
+    chrome::api(&["notifications"])?;
+    let level = chrome::call(|callback| {
+        get_permission_level(callback);
+        Ok(())
+    })
+    .await?;
+    PermissionLevel::try_from(level)
+}
+
+/// Retrieves the ids of all active notifications of this app or extension.
+///
+/// Returns [`Error::NotAvailable`] if `chrome.notifications` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/notifications/#method-getAll)
+///
+pub async fn get_all() -> Result<Vec<String>> {
+    chrome::api(&["notifications"])?;
+    let notifications = chrome::call(|callback| {
+        get_all_with_callback(callback);
+        Ok(())
+    })
+    .await?;
+    if notifications.is_undefined() || notifications.is_null() {
+        return Ok(Vec::new());
+    }
+    Ok(Object::keys(notifications.unchecked_ref::<Object>())
+        .iter()
+        .filter_map(|id| id.as_string())
+        .collect())
+}
+
+/// Listen for permission level changes. The listener is removed
+/// when the returned [`ListenerHandle`] is dropped.
+///
+/// Returns [`Error::NotAvailable`] if `chrome.notifications` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/notifications/#event-onPermissionLevelChanged)
+///
+pub fn on_permission_level_change<F>(mut callback: F) -> Result<ListenerHandle<dyn FnMut(JsValue)>>
+where
+    F: FnMut(PermissionLevel) + 'static,
+{
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |level: JsValue| {
+        match PermissionLevel::try_from(level) {
+            Ok(level) => callback(level),
+            Err(err) => crate::nw::error(&err.to_string()),
+        }
+    });
+    ListenerHandle::try_new(&["notifications", "onPermissionLevelChanged"], closure)
+}
+
+type ClickHandler = Box<dyn FnMut()>;
+type ButtonClickHandler = Box<dyn FnMut(u16)>;
+type CloseHandler = Box<dyn FnMut(bool)>;
+
+/// Rust closures registered for a single notification.
+#[derive(Default)]
+pub(crate) struct Handlers {
+    pub(crate) click: Option<ClickHandler>,
+    pub(crate) button_click: Option<ButtonClickHandler>,
+    pub(crate) close: Option<CloseHandler>,
+}
+
+/// Routes the global `onClicked`, `onButtonClicked` and `onClosed`
+/// events to the [`Handlers`] registered for each notification id.
+struct Router {
+    handlers: HashMap<String, Handlers>,
+    /// Notification being dispatched and whether it was unregistered by its handlers
+    dispatching: Option<(String, bool)>,
+    _clicked: Closure<dyn FnMut(String)>,
+    _button_clicked: Closure<dyn FnMut(String, u16)>,
+    _closed: Closure<dyn FnMut(String, bool)>,
+}
+
+thread_local! {
+    static ROUTER: RefCell<Option<Router>> = const { RefCell::new(None) };
+    static NEXT_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Invoke `f` with the handlers registered for `id`. Handlers are
+/// temporarily removed from the router while `f` executes, allowing
+/// them to create or clear notifications (including their own).
+fn dispatch(id: &str, f: impl FnOnce(&mut Handlers)) {
+    let handlers = ROUTER.with(|router| {
+        let mut router = router.borrow_mut();
+        let router = router.as_mut()?;
+        let handlers = router.handlers.remove(id)?;
+        router.dispatching = Some((id.to_string(), false));
+        Some(handlers)
+    });
+
+    if let Some(mut handlers) = handlers {
+        f(&mut handlers);
+        ROUTER.with(|router| {
+            if let Some(router) = router.borrow_mut().as_mut() {
+                let unregistered = router
+                    .dispatching
+                    .take()
+                    .is_some_and(|(_, unregistered)| unregistered);
+                if !unregistered {
+                    router.handlers.entry(id.to_string()).or_insert(handlers);
+                }
+            }
+        });
+    }
+}
+
+/// Register the global notification event listeners once. Returns
+/// [`Error::NotAvailable`] if `chrome.notifications` is not available.
+fn init_router() -> Result<()> {
+    chrome::api(&["notifications"])?;
+    ROUTER.with(|router| {
+        let mut router = router.borrow_mut();
+        if router.is_some() {
+            return;
+        }
+
+        let clicked = Closure::<dyn FnMut(String)>::new(|id: String| {
+            dispatch(&id, |handlers| {
+                if let Some(click) = handlers.click.as_mut() {
+                    click();
+                }
+            });
+        });
+        let button_clicked = Closure::<dyn FnMut(String, u16)>::new(|id: String, index: u16| {
+            dispatch(&id, |handlers| {
+                if let Some(button_click) = handlers.button_click.as_mut() {
+                    button_click(index);
+                }
+            });
+        });
+        let closed = Closure::<dyn FnMut(String, bool)>::new(|id: String, by_user: bool| {
+            // handlers are released once the notification is closed
+            if let Some(mut handlers) = unregister(&id) {
+                if let Some(close) = handlers.close.as_mut() {
+                    close(by_user);
+                }
+            }
+        });
+
+        on_clicked(clicked.as_ref().unchecked_ref());
+        on_button_clicked(button_clicked.as_ref().unchecked_ref());
+        on_closed(closed.as_ref().unchecked_ref());
+
+        *router = Some(Router {
+            handlers: HashMap::new(),
+            dispatching: None,
+            _clicked: clicked,
+            _button_clicked: button_clicked,
+            _closed: closed,
+        });
+    });
+    Ok(())
+}
+
+/// Register `handlers` for the notification `id`, replacing
+/// previously registered handlers.
+pub(crate) fn register(id: &str, handlers: Handlers) -> Result<()> {
+    init_router()?;
+    ROUTER.with(|router| {
+        if let Some(router) = router.borrow_mut().as_mut() {
+            router.handlers.insert(id.to_string(), handlers);
+        }
+    });
+    Ok(())
+}
+
+/// Remove handlers registered for the notification `id`. If called while
+/// the handlers of `id` are being dispatched, they are released afterwards.
+pub(crate) fn unregister(id: &str) -> Option<Handlers> {
+    ROUTER.with(|router| {
+        let mut router = router.borrow_mut();
+        let router = router.as_mut()?;
+        if let Some((dispatching, unregistered)) = router.dispatching.as_mut() {
+            if dispatching == id {
+                *unregistered = true;
+            }
+        }
+        router.handlers.remove(id)
+    })
+}
+
+/// Generate a unique notification id.
+pub(crate) fn generate_id() -> String {
+    let index = NEXT_ID.with(|next| {
+        let index = next.get();
+        next.set(index + 1);
+        index
+    });
+    format!("nw-sys-{}-{}", js_sys::Date::now() as u64, index)
+}
+
+/// Notification builder registering per-notification Rust closures
+/// for click, button click and close events.
+///
+/// ```rust,ignore
+/// let options = notifications::Options::new()
+///     .set_type(notifications::TemplateType::Basic)
+///     .title("Download complete")
+///     .icon_url("/resources/icons/tray-icon@2x.png")
+///     .message("report.pdf")
+///     .buttons(vec![notifications::Button::new().title("Open")]);
+///
+/// let notification = notifications::Notification::new(options)
+///     .on_click(|| log_info!("clicked"))
+///     .on_button_click(|index| log_info!("button {index} clicked"))
+///     .on_close(|by_user| log_info!("closed, by user: {by_user}"))
+///     .show()?;
+/// ```
+///
+/// Closures are released after the notification is closed.
+pub struct Notification {
+    id: Option<String>,
+    options: Options,
+    handlers: Handlers,
+}
+
+impl Notification {
+    /// Create a notification builder using the given `options`.
+    pub fn new(options: Options) -> Self {
+        Self {
+            id: None,
+            options,
+            handlers: Handlers::default(),
+        }
+    }
+
+    /// Use the given notification `id` instead of a generated one.
+    /// Showing a notification with the id of an existing notification
+    /// replaces it.
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Closure invoked when the user clicks in a non-button area of the notification.
+    pub fn on_click<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut() + 'static,
+    {
+        self.handlers.click = Some(Box::new(callback));
+        self
+    }
+
+    /// Closure invoked with the button index when the user presses
+    /// a button in the notification.
+    pub fn on_button_click<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(u16) + 'static,
+    {
+        self.handlers.button_click = Some(Box::new(callback));
+        self
+    }
+
+    /// Closure invoked when the notification is closed, receiving `true`
+    /// if the notification was closed by the user.
+    pub fn on_close<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(bool) + 'static,
+    {
+        self.handlers.close = Some(Box::new(callback));
+        self
+    }
+
+    /// Create and display the notification.
+    pub fn show(self) -> Result<NotificationHandle> {
+        let id = self.id.unwrap_or_else(generate_id);
+        self.options.validate_create()?;
+        register(&id, self.handlers)?;
+        create(Some(id.clone()), &self.options, None)?;
+        Ok(NotificationHandle { id })
+    }
+}
+
+/// Handle to a notification displayed using [`Notification::show`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationHandle {
+    id: String,
+}
+
+impl NotificationHandle {
+    /// Notification id
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Update the notification.
+    pub fn update(&self, options: &Options) -> Result<()> {
+        update(&self.id, options, None)
+    }
+
+    /// Clear the notification, releasing the registered closures.
+    /// The `on_close` closure is not invoked.
+    pub fn clear(self) -> Result<()> {
+        clear(&self.id, None)
+    }
+}
+
+/// Default minimum interval between progress notification updates in milliseconds
+const PROGRESS_UPDATE_INTERVAL: u32 = 250;
+
+struct ProgressState {
+    id: String,
+    progress: u8,
+    message: String,
+    min_interval: u32,
+    last_update: f64,
+    pending: bool,
+    finished: bool,
+}
+
+impl ProgressState {
+    fn flush(&mut self) {
+        self.pending = false;
+        if self.finished {
+            return;
+        }
+        self.last_update = js_sys::Date::now();
+        let options = Options::new()
+            .progress(self.progress)
+            .message(&self.message);
+        if let Err(err) = update(&self.id, &options, None) {
+            crate::nw::error(&format!("ProgressNotification: {err}"));
+        }
+    }
+}
+
+fn schedule(state: &Rc<RefCell<ProgressState>>) {
+    let mut inner = state.borrow_mut();
+    if inner.finished || inner.pending {
+        return;
+    }
+
+    let elapsed = js_sys::Date::now() - inner.last_update;
+    if elapsed >= inner.min_interval as f64 {
+        inner.flush();
+    } else {
+        inner.pending = true;
+        let weak = Rc::downgrade(state);
+        let timeout = (inner.min_interval as f64 - elapsed).ceil() as u32;
+        let result = crate::utils::set_timeout(timeout, move || {
+            if let Some(state) = weak.upgrade() {
+                state.borrow_mut().flush();
+            }
+        });
+        if result.is_err() {
+            inner.flush();
+        }
+    }
+}
+
+/// Progress notification tied to a long-running task.
+///
+/// Progress and message updates are rate-limited (at most one
+/// [`update`] call per 250ms by default, the latest values are always
+/// delivered, including when the [`ProgressNotification`] is dropped).
+/// [`complete`](Self::complete), [`fail`](Self::fail) and
+/// [`clear`](Self::clear) supersede a pending update. If the user closes
+/// the notification, it is cleared and further updates are ignored.
+///
+/// ```rust,ignore
+/// let progress = notifications::ProgressNotification::new(
+///     "Exporting",
+///     "/resources/icons/tray-icon@2x.png",
+///     "Preparing...",
+/// )?;
+///
+/// progress.set_progress(40);
+/// progress.set_message("Writing report.pdf");
+///
+/// progress.complete("Export complete");
+/// ```
+pub struct ProgressNotification {
+    state: Rc<RefCell<ProgressState>>,
+}
+
+impl ProgressNotification {
+    /// Create and display a progress notification.
+    pub fn new(title: &str, icon_url: &str, message: &str) -> Result<Self> {
+        let options = Options::new()
+            .set_type(TemplateType::Progress)
+            .title(title)
+            .icon_url(icon_url)
+            .message(message)
+            .progress(0);
+        Self::with_options(options)
+    }
+
+    /// Create and display a progress notification using the given `options`.
+    /// The notification type is set to [`TemplateType::Progress`]. The
+    /// `message` of the `options` is displayed until replaced using
+    /// [`ProgressNotification::set_message`].
+    pub fn with_options(options: Options) -> Result<Self> {
+        let options = options.set_type(TemplateType::Progress);
+        options.validate_create()?;
+        let message = match options.field("message") {
+            Some(Field::String(message)) => message,
+            _ => String::new(),
+        };
+
+        let id = generate_id();
+        let state = Rc::new(RefCell::new(ProgressState {
+            id: id.clone(),
+            progress: 0,
+            message,
+            min_interval: PROGRESS_UPDATE_INTERVAL,
+            last_update: js_sys::Date::now(),
+            pending: false,
+            finished: false,
+        }));
+
+        let weak = Rc::downgrade(&state);
+        let close_id = id.clone();
+        register(
+            &id,
+            Handlers {
+                close: Some(Box::new(move |by_user| {
+                    if let Some(state) = weak.upgrade() {
+                        state.borrow_mut().finished = true;
+                    }
+                    if by_user {
+                        if let Err(err) = clear(&close_id, None) {
+                            crate::nw::error(&format!("ProgressNotification: {err}"));
+                        }
+                    }
+                })),
+                ..Default::default()
+            },
+        )?;
+
+        create(Some(id), &options, None)?;
+
+        Ok(Self { state })
+    }
+
+    /// Minimum interval between notification updates in milliseconds.
+    pub fn min_interval(self, min_interval: u32) -> Self {
+        self.state.borrow_mut().min_interval = min_interval;
+        self
+    }
+
+    /// Notification id
+    pub fn id(&self) -> String {
+        self.state.borrow().id.clone()
+    }
+
+    /// Returns `true` if the notification has been completed, failed or closed.
+    pub fn is_finished(&self) -> bool {
+        self.state.borrow().finished
+    }
+
+    /// Set the current progress (0 to 100).
+    pub fn set_progress(&self, progress: u8) {
+        self.state.borrow_mut().progress = progress.min(100);
+        schedule(&self.state);
+    }
+
+    /// Set the notification message.
+    pub fn set_message(&self, message: &str) {
+        self.state.borrow_mut().message = message.to_string();
+        schedule(&self.state);
+    }
+
+    fn finish(self, options: Options) {
+        let mut state = self.state.borrow_mut();
+        if !state.finished {
+            state.finished = true;
+            if let Err(err) = update(&state.id, &options, None) {
+                crate::nw::error(&format!("ProgressNotification: {err}"));
+            }
+        }
+    }
+
+    /// Complete the task, switching the notification to the
+    /// [`TemplateType::Basic`] template displaying `message`.
+    pub fn complete(self, message: &str) {
+        let options = Options::new()
+            .set_type(TemplateType::Basic)
+            .message(message);
+        self.finish(options);
+    }
+
+    /// Mark the task as failed, switching the notification to the
+    /// [`TemplateType::Basic`] template displaying `message`. The
+    /// notification remains visible until dismissed by the user.
+    pub fn fail(self, message: &str) {
+        let options = Options::new()
+            .set_type(TemplateType::Basic)
+            .message(message)
+            .require_interaction(true);
+        self.finish(options);
+    }
+
+    /// Clear the notification.
+    pub fn clear(self) -> Result<()> {
+        let mut state = self.state.borrow_mut();
+        state.finished = true;
+        clear(&state.id, None)
+    }
+}
+
+impl Drop for ProgressNotification {
+    fn drop(&mut self) {
+        // deliver a throttled update that would otherwise be discarded
+        let mut state = self.state.borrow_mut();
+        if state.pending {
+            state.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn fields(entries: &[(&str, Field)]) -> impl Fn(&str) -> Option<Field> {
+        let map: HashMap<String, Field> = entries
+            .iter()
+            .map(|(name, field)| (name.to_string(), field.clone()))
+            .collect();
+        move |name| map.get(name).cloned()
+    }
+
+    fn basic() -> Vec<(&'static str, Field)> {
+        vec![
+            ("type", Field::String("basic".to_string())),
+            ("iconUrl", Field::String("icon.png".to_string())),
+            ("title", Field::String("Title".to_string())),
+            ("message", Field::String("Message".to_string())),
+        ]
+    }
+
+    fn with(
+        mut entries: Vec<(&'static str, Field)>,
+        extra: &[(&'static str, Field)],
+    ) -> Vec<(&'static str, Field)> {
+        for (name, field) in extra {
+            entries.retain(|(existing, _)| existing != name);
+            entries.push((name, field.clone()));
+        }
+        entries
+    }
+
+    fn error(result: Result<()>) -> String {
+        match result {
+            Err(Error::String(message)) => message,
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn accepts_valid_options() {
+        assert!(check_create_fields(&fields(&basic())).is_ok());
+        // partial options for `update`
+        assert!(check_fields(&fields(&[("progress", Field::Number(50.0))])).is_ok());
+        assert!(check_fields(&fields(&[])).is_ok());
+    }
+
+    #[test]
+    fn rejects_missing_required_fields() {
+        for name in ["type", "iconUrl", "title", "message"] {
+            let mut entries = basic();
+            entries.retain(|(existing, _)| *existing != name);
+            assert!(error(check_create_fields(&fields(&entries))).contains(name));
+        }
+    }
+
+    #[test]
+    fn rejects_too_many_buttons() {
+        let entries = with(basic(), &[("buttons", Field::Array(3))]);
+        assert!(error(check_create_fields(&fields(&entries))).contains("buttons"));
+        let entries = with(basic(), &[("buttons", Field::Array(2))]);
+        assert!(check_create_fields(&fields(&entries)).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_progress() {
+        for progress in [
+            Field::Number(-1.0),
+            Field::Number(101.0),
+            Field::Number(f64::NAN),
+            Field::String("50".to_string()),
+        ] {
+            let entries = [("progress", progress)];
+            assert!(error(check_fields(&fields(&entries))).contains("progress"));
+        }
+    }
+
+    #[test]
+    fn rejects_list_without_items() {
+        let list = with(basic(), &[("type", Field::String("list".to_string()))]);
+        assert!(error(check_create_fields(&fields(&list))).contains("item"));
+        let entries = with(list.clone(), &[("items", Field::Array(0))]);
+        assert!(error(check_create_fields(&fields(&entries))).contains("item"));
+        let entries = with(list, &[("items", Field::Array(1))]);
+        assert!(check_create_fields(&fields(&entries)).is_ok());
+    }
+
+    #[test]
+    fn rejects_image_without_image_url() {
+        let image = with(basic(), &[("type", Field::String("image".to_string()))]);
+        assert!(error(check_create_fields(&fields(&image))).contains("imageUrl"));
+        let entries = with(
+            image,
+            &[("imageUrl", Field::String("image.png".to_string()))],
+        );
+        assert!(check_create_fields(&fields(&entries)).is_ok());
+    }
 }
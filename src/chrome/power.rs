@@ -0,0 +1,135 @@
+//!
+//! Chrome power API, allows applications to override the system's power
+//! management features (`chrome.power`).
+//!
+//! `chrome.power` holds a single keep-awake request per application, each
+//! [`request_keep_awake`] call replacing the previous one. [`KeepAwakeGuard`]
+//! reference-counts requests, allowing multiple subsystems to request
+//! wakefulness independently: the strongest requested [`Level`] is applied
+//! while at least one guard is alive and the request is released when the
+//! last guard is dropped.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::power::{KeepAwakeGuard, Level};
+//!
+//! // keep the display awake during playback
+//! let guard = KeepAwakeGuard::new(Level::Display)?;
+//! // ...
+//! drop(guard);
+//! ```
+//!
+
+use crate::chrome;
+use crate::result::Result;
+use std::cell::Cell;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace=["chrome", "power"], js_name="requestKeepAwake")]
+    /// Requests that power management be temporarily disabled. `level`
+    /// is `"display"` or `"system"`. If a request previously made by the
+    /// same app is still active, it will be replaced by the new request.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/power/#method-requestKeepAwake)
+    pub fn request_keep_awake(level: &str);
+
+    #[wasm_bindgen(js_namespace=["chrome", "power"], js_name="releaseKeepAwake")]
+    /// Releases a request previously made via [`request_keep_awake`].
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/power/#method-releaseKeepAwake)
+    pub fn release_keep_awake();
+}
+
+/// Keep-awake level
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/power/#type-Level)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    /// Prevents the display from being turned off or dimmed, or the system
+    /// from sleeping in response to user inactivity
+    Display,
+    /// Prevents the system from sleeping in response to user inactivity
+    System,
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Display => write!(f, "display"),
+            Self::System => write!(f, "system"),
+        }
+    }
+}
+
+thread_local! {
+    static DISPLAY_REQUESTS: Cell<usize> = const { Cell::new(0) };
+    static SYSTEM_REQUESTS: Cell<usize> = const { Cell::new(0) };
+    static APPLIED: Cell<Option<Level>> = const { Cell::new(None) };
+}
+
+fn requests(level: Level) -> &'static std::thread::LocalKey<Cell<usize>> {
+    match level {
+        Level::Display => &DISPLAY_REQUESTS,
+        Level::System => &SYSTEM_REQUESTS,
+    }
+}
+
+/// Apply the strongest level requested by the active guards
+fn apply() {
+    let level = if DISPLAY_REQUESTS.with(Cell::get) > 0 {
+        Some(Level::Display)
+    } else if SYSTEM_REQUESTS.with(Cell::get) > 0 {
+        Some(Level::System)
+    } else {
+        None
+    };
+
+    if APPLIED.with(|applied| applied.replace(level)) != level {
+        match level {
+            Some(level) => request_keep_awake(&level.to_string()),
+            None => release_keep_awake(),
+        }
+    }
+}
+
+/// Returns the keep-awake level currently applied by [`KeepAwakeGuard`]s.
+pub fn active_level() -> Option<Level> {
+    APPLIED.with(Cell::get)
+}
+
+/// Reference-counted keep-awake request.
+///
+/// The request is released when the guard is dropped.
+#[derive(Debug)]
+pub struct KeepAwakeGuard {
+    level: Level,
+}
+
+impl KeepAwakeGuard {
+    /// Request the given keep-awake `level`. Returns
+    /// [`Error::NotAvailable`](crate::error::Error::NotAvailable)
+    /// if `chrome.power` is not available.
+    pub fn new(level: Level) -> Result<Self> {
+        chrome::api(&["power"])?;
+        requests(level).with(|count| count.set(count.get() + 1));
+        apply();
+        Ok(Self { level })
+    }
+
+    /// Requested keep-awake level
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Release the request.
+    pub fn release(self) {}
+}
+
+impl Drop for KeepAwakeGuard {
+    fn drop(&mut self) {
+        requests(self.level).with(|count| count.set(count.get().saturating_sub(1)));
+        apply();
+    }
+}
@@ -0,0 +1,277 @@
+//!
+//! Chrome storage API, allows applications to store, retrieve and track
+//! changes to persistent data (`chrome.storage.local` and `chrome.storage.sync`).
+//!
+//! Values are converted using `serde`, so any type implementing
+//! [`Serialize`] / [`DeserializeOwned`] can be stored.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::storage::Storage;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Settings {
+//!     theme: String,
+//!     zoom: f64,
+//! }
+//!
+//! let storage = Storage::local();
+//! storage.set("settings", &Settings { theme: "dark".into(), zoom: 1.0 }).await?;
+//! let settings: Option<Settings> = storage.get("settings").await?;
+//!
+//! // keep the handle alive to keep receiving changes
+//! let listener = storage.on_changed(|change| {
+//!     if change.key == "settings" {
+//!         let settings: Option<Settings> = change.new_value().unwrap_or_default();
+//!     }
+//! })?;
+//! ```
+//!
+
+use crate::chrome::{self, ListenerHandle};
+use crate::error::Error;
+use crate::result::Result;
+use js_sys::{Array, Function, Object};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Storage area object such as `chrome.storage.local`
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/storage/#type-StorageArea)
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Debug, Clone)]
+    pub type StorageArea;
+
+    #[wasm_bindgen(method, catch)]
+    /// Gets one or more items from storage.
+    ///
+    /// The `keys` parameter is a key, an array of keys, or `null` to get the entire contents.
+    /// The `callback` parameter looks like:
+    /// (items: Object) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-get)
+    pub fn get(
+        this: &StorageArea,
+        keys: &JsValue,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    /// Sets multiple items.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-set)
+    pub fn set(
+        this: &StorageArea,
+        items: &Object,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    /// Removes one or more items from storage.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-remove)
+    pub fn remove(
+        this: &StorageArea,
+        keys: &JsValue,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    /// Removes all items from storage.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-clear)
+    pub fn clear(this: &StorageArea, callback: &Function) -> std::result::Result<(), JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = getBytesInUse)]
+    /// Gets the amount of space (in bytes) being used by one or more items.
+    ///
+    /// The `callback` parameter looks like:
+    /// (bytes_in_use: u64) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/storage/#method-StorageArea-getBytesInUse)
+    pub fn get_bytes_in_use(
+        this: &StorageArea,
+        keys: &JsValue,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+}
+
+/// Storage area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Area {
+    /// Items stored on the local machine
+    Local,
+    /// Items synced using Chrome Sync (local storage when sync is not available)
+    Sync,
+}
+
+impl Area {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Sync => "sync",
+        }
+    }
+}
+
+impl std::fmt::Display for Area {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Serialize `value` into a JSON-compatible value (maps are
+/// serialized as plain objects), as required by the storage API.
+fn to_js<T: Serialize + ?Sized>(value: &T) -> Result<JsValue> {
+    Ok(value.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
+
+fn from_js<T: DeserializeOwned>(value: JsValue) -> Result<Option<T>> {
+    if value.is_undefined() {
+        Ok(None)
+    } else {
+        Ok(Some(serde_wasm_bindgen::from_value(value)?))
+    }
+}
+
+/// Change of a single storage item reported by [`Storage::on_changed`]
+#[derive(Debug, Clone)]
+pub struct StorageChange {
+    /// Item key
+    pub key: String,
+    /// Previous value, `undefined` if the item has been added
+    pub old_value: JsValue,
+    /// New value, `undefined` if the item has been removed
+    pub new_value: JsValue,
+}
+
+impl StorageChange {
+    /// Deserialize the previous value of the item.
+    /// Returns `None` if the item has been added.
+    pub fn old_value<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        from_js(self.old_value.clone())
+    }
+
+    /// Deserialize the new value of the item.
+    /// Returns `None` if the item has been removed.
+    pub fn new_value<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        from_js(self.new_value.clone())
+    }
+}
+
+/// Typed access to a storage area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Storage {
+    area: Area,
+}
+
+impl Storage {
+    /// `chrome.storage.local` storage
+    pub fn local() -> Self {
+        Self { area: Area::Local }
+    }
+
+    /// `chrome.storage.sync` storage
+    pub fn sync() -> Self {
+        Self { area: Area::Sync }
+    }
+
+    /// Storage area
+    pub fn area(&self) -> Area {
+        self.area
+    }
+
+    /// Returns `true` if the storage area is available.
+    pub fn is_available(&self) -> bool {
+        chrome::is_available(&["storage", self.area.name()])
+    }
+
+    /// Get the underlying [`StorageArea`] object. Returns
+    /// [`Error::NotAvailable`] if the storage area is not available.
+    pub fn storage_area(&self) -> Result<StorageArea> {
+        Ok(chrome::api(&["storage", self.area.name()])?.unchecked_into())
+    }
+
+    /// Get the item stored under `key`. Returns `None` if the item does not exist.
+    pub async fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        let area = self.storage_area()?;
+        let items = chrome::call(|callback| area.get(&JsValue::from(key), callback)).await?;
+        from_js(js_sys::Reflect::get(&items, &JsValue::from(key))?)
+    }
+
+    /// Store `value` under `key`.
+    pub async fn set<T: Serialize + ?Sized>(&self, key: &str, value: &T) -> Result<()> {
+        let area = self.storage_area()?;
+        let items = Object::new();
+        js_sys::Reflect::set(&items, &JsValue::from(key), &to_js(value)?)?;
+        chrome::call(|callback| area.set(&items, callback)).await?;
+        Ok(())
+    }
+
+    /// Remove the item stored under `key`.
+    pub async fn remove(&self, key: &str) -> Result<()> {
+        let area = self.storage_area()?;
+        chrome::call(|callback| area.remove(&JsValue::from(key), callback)).await?;
+        Ok(())
+    }
+
+    /// Remove all items from the storage area.
+    pub async fn clear(&self) -> Result<()> {
+        let area = self.storage_area()?;
+        chrome::call(|callback| area.clear(callback)).await?;
+        Ok(())
+    }
+
+    /// Get the amount of space (in bytes) used by the items stored under `keys`,
+    /// or by the entire storage area if `keys` is empty.
+    pub async fn get_bytes_in_use(&self, keys: &[&str]) -> Result<u64> {
+        let area = self.storage_area()?;
+        let keys = if keys.is_empty() {
+            JsValue::NULL
+        } else {
+            keys.iter()
+                .map(|key| JsValue::from(*key))
+                .collect::<Array>()
+                .into()
+        };
+        let bytes = chrome::call(|callback| area.get_bytes_in_use(&keys, callback)).await?;
+        bytes
+            .as_f64()
+            .map(|bytes| bytes as u64)
+            .ok_or_else(|| Error::String(format!("invalid storage bytes in use: {bytes:?}")))
+    }
+
+    /// Listen for changes to items in the storage area. `callback` is
+    /// invoked for each changed item. The listener is removed when the
+    /// returned [`ListenerHandle`] is dropped.
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/storage/#event-StorageArea-onChanged)
+    pub fn on_changed<F>(&self, mut callback: F) -> Result<ListenerHandle<dyn FnMut(Object)>>
+    where
+        F: FnMut(StorageChange) + 'static,
+    {
+        let closure = Closure::<dyn FnMut(Object)>::new(move |changes: Object| {
+            for key in Object::keys(&changes).iter() {
+                let Some(key) = key.as_string() else {
+                    continue;
+                };
+                let change = js_sys::Reflect::get(&changes, &JsValue::from(&key))
+                    .unwrap_or(JsValue::UNDEFINED);
+                let get = |prop: &str| {
+                    js_sys::Reflect::get(&change, &JsValue::from(prop))
+                        .unwrap_or(JsValue::UNDEFINED)
+                };
+                callback(StorageChange {
+                    old_value: get("oldValue"),
+                    new_value: get("newValue"),
+                    key,
+                });
+            }
+        });
+        ListenerHandle::try_new(&["storage", self.area.name(), "onChanged"], closure)
+    }
+}
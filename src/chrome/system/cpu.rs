@@ -0,0 +1,120 @@
+//!
+//! Chrome system CPU API, allows querying CPU metadata (`chrome.system.cpu`).
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! let info = nw_sys::chrome::system::cpu::get_info().await?;
+//! log_info!("{} ({}), {} processors", info.model, info.arch, info.processors.len());
+//! ```
+//!
+
+use crate::chrome;
+use crate::result::Result;
+use crate::utils;
+use js_sys::{Array, Function};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace=["chrome", "system", "cpu"], js_name="getInfo")]
+    /// Queries basic CPU information of the system.
+    ///
+    /// The `callback` parameter looks like:
+    /// (info: CpuInfo) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_cpu/#method-getInfo)
+    pub fn get_info_with_callback(callback: &Function);
+}
+
+/// Cumulative processor usage, in milliseconds, since the system started
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_cpu/#type-CpuTime)
+#[derive(Debug, Clone)]
+pub struct ProcessorUsage {
+    /// Time spent by userspace programs
+    pub user: f64,
+    /// Time spent by the kernel
+    pub kernel: f64,
+    /// Time spent in idle mode
+    pub idle: f64,
+    /// Total time (`user + kernel + idle`)
+    pub total: f64,
+}
+
+impl ProcessorUsage {
+    /// Ratio (`0.0..=1.0`) of non-idle time between this
+    /// and an `earlier` sample of the same processor.
+    pub fn load_since(&self, earlier: &ProcessorUsage) -> f64 {
+        let total = self.total - earlier.total;
+        if total <= 0.0 {
+            0.0
+        } else {
+            (1.0 - (self.idle - earlier.idle) / total).clamp(0.0, 1.0)
+        }
+    }
+}
+
+impl TryFrom<JsValue> for ProcessorUsage {
+    type Error = crate::error::Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let usage = utils::try_get_js_value(&jsv, "usage")?;
+        Ok(Self {
+            user: utils::try_get_f64_from_prop(&usage, "user")?,
+            kernel: utils::try_get_f64_from_prop(&usage, "kernel")?,
+            idle: utils::try_get_f64_from_prop(&usage, "idle")?,
+            total: utils::try_get_f64_from_prop(&usage, "total")?,
+        })
+    }
+}
+
+/// CPU information
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_cpu/#type-CpuInfo)
+#[derive(Debug, Clone)]
+pub struct CpuInfo {
+    /// Architecture name of the processors
+    pub arch: String,
+    /// Model name of the processors
+    pub model: String,
+    /// Processor feature codes (e.g. `sse`, `avx`)
+    pub features: Vec<String>,
+    /// Usage of each logical processor
+    pub processors: Vec<ProcessorUsage>,
+}
+
+impl TryFrom<JsValue> for CpuInfo {
+    type Error = crate::error::Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let features = Array::from(&utils::try_get_js_value(&jsv, "features")?)
+            .iter()
+            .filter_map(|feature| feature.as_string())
+            .collect();
+        let processors = Array::from(&utils::try_get_js_value(&jsv, "processors")?)
+            .iter()
+            .map(ProcessorUsage::try_from)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            arch: utils::try_get_string_from_prop(&jsv, "archName")?,
+            model: utils::try_get_string_from_prop(&jsv, "modelName")?,
+            features,
+            processors,
+        })
+    }
+}
+
+/// Query the CPU information.
+///
+/// Returns [`Error::NotAvailable`](crate::error::Error::NotAvailable)
+/// if `chrome.system.cpu` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_cpu/#method-getInfo)
+pub async fn get_info() -> Result<CpuInfo> {
+    chrome::api(&["system", "cpu"])?;
+    let info = chrome::call(|callback| {
+        get_info_with_callback(callback);
+        Ok(())
+    })
+    .await?;
+    CpuInfo::try_from(info)
+}
@@ -0,0 +1,218 @@
+//!
+//! Chrome system display API, allows querying display metadata
+//! (`chrome.system.display`).
+//!
+//! [`DisplayUnitInfo`] complements [`ScreenInfo`] with the display name,
+//! mirroring, overscan, DPI and primary flag. [`get_displays`] merges both
+//! by display id.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::system::display;
+//!
+//! for display in display::get_displays().await? {
+//!     let name = display.unit.as_ref().map(|unit| unit.name.as_str());
+//!     log_info!("{:?} {:?} primary: {}", display.screen.id, name, display.is_primary());
+//! }
+//!
+//! // keep the handle alive to keep receiving events
+//! let listener = display::on_display_changed(|| log_info!("display configuration changed"))?;
+//! ```
+//!
+
+use crate::chrome::{self, ListenerHandle};
+use crate::result::Result;
+use crate::screen::{self, Bounds, Rotation, ScreenInfo, WorkArea};
+use crate::utils;
+use js_sys::{Array, Function};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace=["chrome", "system", "display"], js_name="getInfo")]
+    /// Requests the information for all attached display devices.
+    ///
+    /// The `callback` parameter looks like:
+    /// (info: DisplayUnitInfo[]) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_display/#method-getInfo)
+    pub fn get_info_with_callback(callback: &Function);
+}
+
+/// Display insets in DIP
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_display/#type-Insets)
+#[derive(Debug, Clone, Default)]
+pub struct Insets {
+    pub left: f64,
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+}
+
+/// Display information
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_display/#type-DisplayUnitInfo)
+#[derive(Debug, Clone)]
+pub struct DisplayUnitInfo {
+    /// Unique identifier of the display
+    pub id: String,
+    /// User-friendly name (e.g. "HP LCD monitor")
+    pub name: String,
+    /// Identifier of the display that is being mirrored, empty if mirroring is not enabled
+    pub mirroring_source_id: String,
+    pub is_primary: bool,
+    /// `true` if this is an internal display
+    pub is_internal: bool,
+    pub is_enabled: bool,
+    /// Number of pixels per inch along the x-axis
+    pub dpi_x: f64,
+    /// Number of pixels per inch along the y-axis
+    pub dpi_y: f64,
+    pub rotation: Option<Rotation>,
+    pub bounds: Bounds,
+    /// Display's insets within its screen's bounds
+    pub overscan: Insets,
+    pub work_area: WorkArea,
+}
+
+impl DisplayUnitInfo {
+    /// Returns `true` if this display is the display described by `screen`.
+    ///
+    /// [`ScreenInfo::id`] is read from a JavaScript number, so display ids
+    /// above 2^53 are rounded; the id is compared with the same rounding.
+    pub fn matches(&self, screen: &ScreenInfo) -> bool {
+        self.id.parse::<f64>().ok().map(|id| id as u64) == Some(screen.id)
+    }
+
+    /// Returns `true` if this display mirrors another display.
+    pub fn is_mirroring(&self) -> bool {
+        !self.mirroring_source_id.is_empty()
+    }
+}
+
+/// Read a `{ left, top, width, height }` object
+fn read_bounds(jsv: &JsValue, prop: &str) -> Result<(f64, f64, f64, f64)> {
+    let jsv = utils::try_get_js_value(jsv, prop)?;
+    Ok((
+        utils::try_get_f64_from_prop(&jsv, "left")?,
+        utils::try_get_f64_from_prop(&jsv, "top")?,
+        utils::try_get_f64_from_prop(&jsv, "width")?,
+        utils::try_get_f64_from_prop(&jsv, "height")?,
+    ))
+}
+
+impl TryFrom<JsValue> for DisplayUnitInfo {
+    type Error = crate::error::Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let (x, y, width, height) = read_bounds(&jsv, "bounds")?;
+        let bounds = Bounds {
+            x,
+            y,
+            width,
+            height,
+        };
+
+        let (x, y, width, height) = read_bounds(&jsv, "workArea")?;
+        let work_area = WorkArea {
+            x,
+            y,
+            width,
+            height,
+        };
+
+        let overscan = utils::try_get_js_value(&jsv, "overscan")?;
+        let overscan = if overscan.is_object() {
+            Insets {
+                left: utils::try_get_f64_from_prop(&overscan, "left")?,
+                top: utils::try_get_f64_from_prop(&overscan, "top")?,
+                right: utils::try_get_f64_from_prop(&overscan, "right")?,
+                bottom: utils::try_get_f64_from_prop(&overscan, "bottom")?,
+            }
+        } else {
+            Insets::default()
+        };
+
+        Ok(Self {
+            id: utils::try_get_string_from_prop(&jsv, "id")?,
+            name: utils::try_get_string_from_prop(&jsv, "name")?,
+            mirroring_source_id: utils::try_get_string_from_prop(&jsv, "mirroringSourceId")
+                .unwrap_or_default(),
+            is_primary: utils::try_get_bool_from_prop(&jsv, "isPrimary")?,
+            is_internal: utils::try_get_bool_from_prop(&jsv, "isInternal").unwrap_or(false),
+            is_enabled: utils::try_get_bool_from_prop(&jsv, "isEnabled").unwrap_or(true),
+            dpi_x: utils::try_get_f64_from_prop(&jsv, "dpiX")?,
+            dpi_y: utils::try_get_f64_from_prop(&jsv, "dpiY")?,
+            rotation: utils::try_get_u64_from_prop(&jsv, "rotation")
+                .ok()
+                .and_then(Rotation::from_degrees),
+            bounds,
+            overscan,
+            work_area,
+        })
+    }
+}
+
+/// Query the information for all attached displays.
+///
+/// Returns [`Error::NotAvailable`](crate::error::Error::NotAvailable)
+/// if `chrome.system.display` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_display/#method-getInfo)
+pub async fn get_info() -> Result<Vec<DisplayUnitInfo>> {
+    chrome::api(&["system", "display"])?;
+    let displays = chrome::call(|callback| {
+        get_info_with_callback(callback);
+        Ok(())
+    })
+    .await?;
+    Array::from(&displays)
+        .iter()
+        .map(DisplayUnitInfo::try_from)
+        .collect()
+}
+
+/// Listen for display configuration changes. The listener is removed
+/// when the returned [`ListenerHandle`] is dropped.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_display/#event-onDisplayChanged)
+pub fn on_display_changed<F>(callback: F) -> Result<ListenerHandle<dyn FnMut()>>
+where
+    F: FnMut() + 'static,
+{
+    let closure = Closure::<dyn FnMut()>::new(callback);
+    ListenerHandle::try_new(&["system", "display", "onDisplayChanged"], closure)
+}
+
+/// [`ScreenInfo`] merged with the matching [`DisplayUnitInfo`]
+#[derive(Debug, Clone)]
+pub struct Display {
+    pub screen: ScreenInfo,
+    /// Display information, `None` if no display with the screen id was reported
+    pub unit: Option<DisplayUnitInfo>,
+}
+
+impl Display {
+    /// Returns `true` if the display is the primary display.
+    pub fn is_primary(&self) -> bool {
+        self.unit.as_ref().is_some_and(|unit| unit.is_primary)
+    }
+}
+
+/// Merge `screens` with the matching `displays` by display id.
+pub fn merge(screens: Vec<ScreenInfo>, displays: &[DisplayUnitInfo]) -> Vec<Display> {
+    screens
+        .into_iter()
+        .map(|screen| {
+            let unit = displays.iter().find(|unit| unit.matches(&screen)).cloned();
+            Display { screen, unit }
+        })
+        .collect()
+}
+
+/// Query [`screen::screens`] and merge them with the display
+/// information reported by [`get_info`].
+pub async fn get_displays() -> Result<Vec<Display>> {
+    let displays = get_info().await?;
+    Ok(merge(screen::screens()?, &displays))
+}
@@ -0,0 +1,64 @@
+//!
+//! Chrome system memory API (`chrome.system.memory`).
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! let info = nw_sys::chrome::system::memory::get_info().await?;
+//! log_info!("{} of {} bytes available", info.available, info.capacity);
+//! ```
+//!
+
+use crate::chrome;
+use crate::result::Result;
+use crate::utils;
+use js_sys::Function;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace=["chrome", "system", "memory"], js_name="getInfo")]
+    /// Get physical memory information.
+    ///
+    /// The `callback` parameter looks like:
+    /// (info: MemoryInfo) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_memory/#method-getInfo)
+    pub fn get_info_with_callback(callback: &Function);
+}
+
+/// Physical memory information
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_memory/#type-MemoryInfo)
+#[derive(Debug, Clone)]
+pub struct MemoryInfo {
+    /// Total amount of physical memory capacity in bytes
+    pub capacity: u64,
+    /// Amount of available capacity in bytes
+    pub available: u64,
+}
+
+impl TryFrom<JsValue> for MemoryInfo {
+    type Error = crate::error::Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            capacity: utils::try_get_u64_from_prop(&jsv, "capacity")?,
+            available: utils::try_get_u64_from_prop(&jsv, "availableCapacity")?,
+        })
+    }
+}
+
+/// Query the physical memory information.
+///
+/// Returns [`Error::NotAvailable`](crate::error::Error::NotAvailable)
+/// if `chrome.system.memory` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_memory/#method-getInfo)
+pub async fn get_info() -> Result<MemoryInfo> {
+    chrome::api(&["system", "memory"])?;
+    let info = chrome::call(|callback| {
+        get_info_with_callback(callback);
+        Ok(())
+    })
+    .await?;
+    MemoryInfo::try_from(info)
+}
@@ -0,0 +1,8 @@
+//!
+//! Chrome system information APIs (`chrome.system.*`).
+//!
+
+pub mod cpu;
+pub mod display;
+pub mod memory;
+pub mod storage;
@@ -0,0 +1,135 @@
+//!
+//! Chrome system storage API, allows querying storage device information
+//! and being notified when a removable storage device is attached or
+//! detached (`chrome.system.storage`).
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::chrome::system::storage;
+//!
+//! for unit in storage::get_info().await? {
+//!     log_info!("{} {:?} {} bytes", unit.name, unit.kind, unit.capacity);
+//! }
+//!
+//! // keep the handles alive to keep receiving events
+//! let attached = storage::on_attached(|unit| log_info!("attached: {}", unit.name))?;
+//! let detached = storage::on_detached(|id| log_info!("detached: {id}"))?;
+//! ```
+//!
+
+use crate::chrome::{self, ListenerHandle};
+use crate::result::Result;
+use crate::utils;
+use js_sys::{Array, Function};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace=["chrome", "system", "storage"], js_name="getInfo")]
+    /// Get the storage information from the system.
+    ///
+    /// The `callback` parameter looks like:
+    /// (info: StorageUnitInfo[]) => ()
+    ///
+    /// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_storage/#method-getInfo)
+    pub fn get_info_with_callback(callback: &Function);
+}
+
+/// Storage device type
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_storage/#type-StorageUnitType)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageUnitKind {
+    /// Fixed media, e.g. hard disk or SSD
+    Fixed,
+    /// Removable media, e.g. USB flash drive
+    Removable,
+    /// Unknown storage type
+    Unknown,
+}
+
+impl From<&str> for StorageUnitKind {
+    fn from(kind: &str) -> Self {
+        match kind {
+            "fixed" => Self::Fixed,
+            "removable" => Self::Removable,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Storage device information
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_storage/#type-StorageUnitInfo)
+#[derive(Debug, Clone)]
+pub struct StorageUnit {
+    /// Transient id that uniquely identifies the storage device
+    pub id: String,
+    /// Name of the storage unit
+    pub name: String,
+    pub kind: StorageUnitKind,
+    /// Total amount of storage space in bytes
+    pub capacity: u64,
+}
+
+impl TryFrom<JsValue> for StorageUnit {
+    type Error = crate::error::Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let kind = utils::try_get_string_from_prop(&jsv, "type")?;
+        Ok(Self {
+            id: utils::try_get_string_from_prop(&jsv, "id")?,
+            name: utils::try_get_string_from_prop(&jsv, "name")?,
+            kind: StorageUnitKind::from(kind.as_str()),
+            capacity: utils::try_get_u64_from_prop(&jsv, "capacity")?,
+        })
+    }
+}
+
+/// Query the storage devices attached to the system.
+///
+/// Returns [`Error::NotAvailable`](crate::error::Error::NotAvailable)
+/// if `chrome.system.storage` is not available.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_storage/#method-getInfo)
+pub async fn get_info() -> Result<Vec<StorageUnit>> {
+    chrome::api(&["system", "storage"])?;
+    let units = chrome::call(|callback| {
+        get_info_with_callback(callback);
+        Ok(())
+    })
+    .await?;
+    Array::from(&units)
+        .iter()
+        .map(StorageUnit::try_from)
+        .collect()
+}
+
+/// Listen for new removable storage devices being attached to the system.
+/// The listener is removed when the returned [`ListenerHandle`] is dropped.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_storage/#event-onAttached)
+pub fn on_attached<F>(mut callback: F) -> Result<ListenerHandle<dyn FnMut(JsValue)>>
+where
+    F: FnMut(StorageUnit) + 'static,
+{
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |info: JsValue| {
+        match StorageUnit::try_from(info) {
+            Ok(unit) => callback(unit),
+            Err(err) => crate::nw::error(&err.to_string()),
+        }
+    });
+    ListenerHandle::try_new(&["system", "storage", "onAttached"], closure)
+}
+
+/// Listen for removable storage devices being detached from the system.
+/// `callback` receives the id of the detached [`StorageUnit`]. The listener
+/// is removed when the returned [`ListenerHandle`] is dropped.
+///
+/// [Chrome Doc](https://developer.chrome.com/docs/extensions/reference/system_storage/#event-onDetached)
+pub fn on_detached<F>(mut callback: F) -> Result<ListenerHandle<dyn FnMut(String)>>
+where
+    F: FnMut(String) + 'static,
+{
+    let closure = Closure::<dyn FnMut(String)>::new(move |id: String| callback(id));
+    ListenerHandle::try_new(&["system", "storage", "onDetached"], closure)
+}
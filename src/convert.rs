@@ -0,0 +1,119 @@
+//!
+//! [`FromJs`] trait used to decode [`JsValue`] results returned by
+//! the NWJS APIs into Rust types.
+//!
+
+use crate::error::Error;
+use crate::result::Result;
+use js_sys::{Array, Object};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Conversion of a [`JsValue`] into a Rust type.
+pub trait FromJs: Sized {
+    /// Decode `value` into `Self`, returning an error if the value
+    /// is not of the expected type.
+    fn from_js(value: JsValue) -> Result<Self>;
+}
+
+fn type_error(expected: &str, value: &JsValue) -> Error {
+    Error::String(format!("expected {expected}, got '{:?}'", value))
+}
+
+impl FromJs for JsValue {
+    fn from_js(value: JsValue) -> Result<Self> {
+        Ok(value)
+    }
+}
+
+impl FromJs for () {
+    fn from_js(_value: JsValue) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl FromJs for bool {
+    fn from_js(value: JsValue) -> Result<Self> {
+        value.as_bool().ok_or_else(|| type_error("boolean", &value))
+    }
+}
+
+impl FromJs for String {
+    fn from_js(value: JsValue) -> Result<Self> {
+        value
+            .as_string()
+            .ok_or_else(|| type_error("string", &value))
+    }
+}
+
+impl FromJs for f64 {
+    fn from_js(value: JsValue) -> Result<Self> {
+        value.as_f64().ok_or_else(|| type_error("number", &value))
+    }
+}
+
+impl FromJs for f32 {
+    fn from_js(value: JsValue) -> Result<Self> {
+        Ok(f64::from_js(value)? as f32)
+    }
+}
+
+macro_rules! impl_from_js_for_integer {
+    ($($t:ty),*) => {
+        $(
+            impl FromJs for $t {
+                fn from_js(value: JsValue) -> Result<Self> {
+                    let v = value
+                        .as_f64()
+                        .ok_or_else(|| type_error(stringify!($t), &value))?;
+                    // `MAX as f64` rounds up to 2^63 / 2^64 for i64 / u64, so the
+                    // exclusive bound `MAX + 1` is used (exact for all types)
+                    if v.fract() != 0.0 || v < <$t>::MIN as f64 || v >= <$t>::MAX as f64 + 1.0 {
+                        return Err(type_error(stringify!($t), &value));
+                    }
+                    Ok(v as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_js_for_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl FromJs for Object {
+    fn from_js(value: JsValue) -> Result<Self> {
+        value
+            .dyn_into::<Object>()
+            .map_err(|value| type_error("object", &value))
+    }
+}
+
+impl FromJs for Array {
+    fn from_js(value: JsValue) -> Result<Self> {
+        value
+            .dyn_into::<Array>()
+            .map_err(|value| type_error("array", &value))
+    }
+}
+
+impl<T> FromJs for Option<T>
+where
+    T: FromJs,
+{
+    fn from_js(value: JsValue) -> Result<Self> {
+        if value.is_undefined() || value.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(T::from_js(value)?))
+        }
+    }
+}
+
+impl<T> FromJs for Vec<T>
+where
+    T: FromJs,
+{
+    fn from_js(value: JsValue) -> Result<Self> {
+        Array::from_js(value)?.iter().map(T::from_js).collect()
+    }
+}
@@ -4,7 +4,7 @@
 
 use std::sync::PoisonError;
 use thiserror::Error;
-use wasm_bindgen::JsValue;
+use wasm_bindgen::{JsCast, JsValue};
 
 #[derive(Debug, Error)]
 pub enum Error {
@@ -16,6 +16,41 @@ pub enum Error {
 
     #[error("Poison Error: {0}")]
     PoisonError(String),
+
+    /// An exception thrown by JavaScript code, carrying
+    /// the exception message and the JavaScript stack trace (if available).
+    #[error("JavaScript Exception: {message}")]
+    Exception {
+        message: String,
+        stack: Option<String>,
+    },
+
+    /// The requested API is not available in the current environment
+    /// (for example `chrome.notifications` when running in a regular browser).
+    #[error("API not available: {0}")]
+    NotAvailable(String),
+
+    /// The operation was cancelled by the user
+    /// (for example by dismissing the desktop capture picker).
+    #[error("Cancelled by the user")]
+    Cancelled,
+
+    #[error("Serialization Error: {0}")]
+    Serde(String),
+}
+
+impl Error {
+    /// Create an [`Error::Exception`] from a value thrown by JavaScript.
+    pub fn exception(err: JsValue) -> Self {
+        let stack = js_sys::Reflect::get(&err, &JsValue::from("stack"))
+            .ok()
+            .and_then(|stack| stack.as_string());
+        let message = match err.dyn_ref::<js_sys::Error>() {
+            Some(err) => String::from(err.message()),
+            None => err.as_string().unwrap_or_else(|| format!("{:?}", err)),
+        };
+        Self::Exception { message, stack }
+    }
 }
 
 impl From<String> for Error {
@@ -35,6 +70,12 @@ impl From<JsValue> for Error {
     }
 }
 
+impl From<serde_wasm_bindgen::Error> for Error {
+    fn from(err: serde_wasm_bindgen::Error) -> Self {
+        Self::Serde(err.to_string())
+    }
+}
+
 impl<T> From<PoisonError<T>> for Error
 where
     T: std::fmt::Debug,
@@ -47,7 +88,13 @@ where
 impl From<Error> for String {
     fn from(err: Error) -> String {
         match err {
-            Error::String(s) | Error::PoisonError(s) | Error::JsValue(s) => s,
+            Error::String(s) | Error::PoisonError(s) | Error::JsValue(s) | Error::Serde(s) => s,
+            Error::NotAvailable(api) => format!("API not available: {api}"),
+            Error::Cancelled => "Cancelled by the user".to_string(),
+            Error::Exception { message, stack } => match stack {
+                Some(stack) => format!("{message}\n{stack}"),
+                None => message,
+            },
         }
     }
 }
@@ -0,0 +1,280 @@
+//!
+//! Kiosk / presentation mode controller. [`KioskController`] enters a
+//! locked-down mode in a single step (kiosk mode, always-on-top, dev tools
+//! closed, escape shortcuts captured via global hotkeys and the context menu
+//! suppressed) and restores the prior window state when the mode is exited.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::kiosk::{KioskController, KioskOptions};
+//!
+//! let options = KioskOptions::default()
+//!     .visible_on_all_workspaces(true)
+//!     .blocked_shortcuts(&["Escape", "F11", "Alt+F4"]);
+//!
+//! let mut kiosk = KioskController::new(&nw_sys::window::get(), options);
+//! kiosk.enter()?;
+//!
+//! // ... later, for example after an administrator PIN has been entered
+//! kiosk.exit()?;
+//! ```
+//!
+
+use crate::app;
+use crate::options::OptionsTrait;
+use crate::result::Result;
+use crate::shortcut::{self, Shortcut};
+use crate::window::Window;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Shortcuts captured by default while the kiosk mode is active.
+pub const DEFAULT_BLOCKED_SHORTCUTS: &[&str] = &[
+    "Escape",
+    "F11",
+    "Alt+F4",
+    "Ctrl+W",
+    "Ctrl+Q",
+    "Command+W",
+    "Command+Q",
+];
+
+/// Kiosk mode configuration used by [`KioskController`].
+#[derive(Debug, Clone)]
+pub struct KioskOptions {
+    always_on_top: bool,
+    visible_on_all_workspaces: bool,
+    hide_from_taskbar: bool,
+    close_dev_tools: bool,
+    suppress_context_menu: bool,
+    blocked_shortcuts: Vec<String>,
+}
+
+impl Default for KioskOptions {
+    fn default() -> Self {
+        Self {
+            always_on_top: true,
+            visible_on_all_workspaces: false,
+            hide_from_taskbar: false,
+            close_dev_tools: true,
+            suppress_context_menu: true,
+            blocked_shortcuts: DEFAULT_BLOCKED_SHORTCUTS
+                .iter()
+                .map(|key| key.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl KioskOptions {
+    /// Keep the window on top of other windows. `true` by default.
+    pub fn always_on_top(mut self, always_on_top: bool) -> Self {
+        self.always_on_top = always_on_top;
+        self
+    }
+
+    /// (Mac and Linux) Make the window visible on all workspaces. `false` by default.
+    ///
+    /// The prior state can not be queried; on exit the window is made
+    /// visible on the current workspace only.
+    pub fn visible_on_all_workspaces(mut self, visible: bool) -> Self {
+        self.visible_on_all_workspaces = visible;
+        self
+    }
+
+    /// Hide the window from the taskbar or dock. `false` by default.
+    ///
+    /// The prior state can not be queried; on exit the window is shown
+    /// in the taskbar.
+    pub fn hide_from_taskbar(mut self, hide: bool) -> Self {
+        self.hide_from_taskbar = hide;
+        self
+    }
+
+    /// Close dev tools when entering the kiosk mode. Dev tools are
+    /// re-opened on exit if they were open before. `true` by default.
+    pub fn close_dev_tools(mut self, close: bool) -> Self {
+        self.close_dev_tools = close;
+        self
+    }
+
+    /// Suppress the page context menu. `true` by default.
+    pub fn suppress_context_menu(mut self, suppress: bool) -> Self {
+        self.suppress_context_menu = suppress;
+        self
+    }
+
+    /// Keyboard shortcuts (in the [`shortcut::Options::key`] format) captured as
+    /// global hotkeys while the kiosk mode is active, preventing them from
+    /// reaching the system or the application.
+    /// Defaults to [`DEFAULT_BLOCKED_SHORTCUTS`].
+    pub fn blocked_shortcuts(mut self, keys: &[&str]) -> Self {
+        self.blocked_shortcuts = keys.iter().map(|key| key.to_string()).collect();
+        self
+    }
+}
+
+/// Window state captured before entering the kiosk mode.
+#[derive(Debug, Clone)]
+struct PriorState {
+    kiosk: bool,
+    fullscreen: bool,
+    always_on_top: bool,
+    dev_tools_open: bool,
+}
+
+struct Active {
+    prior: PriorState,
+    shortcuts: Vec<Shortcut>,
+    context_menu: Option<Closure<dyn FnMut(web_sys::Event)>>,
+}
+
+/// Controller entering and leaving the locked-down kiosk mode for a [`Window`].
+///
+/// The kiosk mode is exited when the controller is dropped.
+pub struct KioskController {
+    window: Window,
+    options: KioskOptions,
+    active: Option<Active>,
+}
+
+impl KioskController {
+    /// Create a kiosk controller for the given `window`.
+    pub fn new(window: &Window, options: KioskOptions) -> Self {
+        Self {
+            window: window.clone(),
+            options,
+            active: None,
+        }
+    }
+
+    /// Returns `true` if the kiosk mode is currently active.
+    pub fn is_active(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Enter the kiosk mode. Does nothing if the kiosk mode is already active.
+    pub fn enter(&mut self) -> Result<()> {
+        if self.active.is_some() {
+            return Ok(());
+        }
+
+        let window = &self.window;
+        let options = &self.options;
+        let prior = PriorState {
+            kiosk: window.is_kiosk_mode(),
+            fullscreen: window.is_fullscreen(),
+            always_on_top: window.is_always_on_top(),
+            dev_tools_open: window.is_dev_tools_open(),
+        };
+
+        let context_menu = if options.suppress_context_menu {
+            let closure = Closure::<dyn FnMut(web_sys::Event)>::new(|event: web_sys::Event| {
+                event.prevent_default();
+            });
+            window.window().add_event_listener_with_callback(
+                "contextmenu",
+                closure.as_ref().unchecked_ref(),
+            )?;
+            Some(closure)
+        } else {
+            None
+        };
+
+        let shortcuts = options
+            .blocked_shortcuts
+            .iter()
+            .map(|key| {
+                let shortcut = Shortcut::new(&shortcut::Options::new().key(key));
+                app::register_global_hot_key(&shortcut);
+                shortcut
+            })
+            .collect();
+
+        if options.close_dev_tools && prior.dev_tools_open {
+            window.close_dev_tools();
+        }
+        if options.always_on_top {
+            window.set_always_on_top(true);
+        }
+        if options.visible_on_all_workspaces && window.can_set_visible_on_all_workspaces() {
+            window.set_visible_on_all_workspaces(true);
+        }
+        if options.hide_from_taskbar {
+            window.set_show_in_taskbar(false);
+        }
+        if !prior.kiosk {
+            window.enter_kiosk_mode();
+        }
+
+        self.active = Some(Active {
+            prior,
+            shortcuts,
+            context_menu,
+        });
+
+        Ok(())
+    }
+
+    /// Exit the kiosk mode restoring the window state captured by [`KioskController::enter`].
+    /// Does nothing if the kiosk mode is not active.
+    pub fn exit(&mut self) -> Result<()> {
+        let Some(active) = self.active.take() else {
+            return Ok(());
+        };
+
+        let window = &self.window;
+        let options = &self.options;
+        let Active {
+            prior,
+            shortcuts,
+            context_menu,
+        } = active;
+
+        for shortcut in shortcuts.iter() {
+            app::unregister_global_hot_key(shortcut);
+        }
+
+        if !prior.kiosk {
+            window.leave_kiosk_mode();
+        }
+        if prior.fullscreen && !window.is_fullscreen() {
+            window.enter_fullscreen();
+        } else if !prior.fullscreen && window.is_fullscreen() {
+            window.leave_fullscreen();
+        }
+        if options.hide_from_taskbar {
+            window.set_show_in_taskbar(true);
+        }
+        if options.visible_on_all_workspaces && window.can_set_visible_on_all_workspaces() {
+            window.set_visible_on_all_workspaces(false);
+        }
+        if options.always_on_top {
+            window.set_always_on_top(prior.always_on_top);
+        }
+        if options.close_dev_tools && prior.dev_tools_open {
+            window.show_dev_tools();
+        }
+
+        // restore the window state first so that a failure to remove
+        // the listener does not leave the window stuck in kiosk mode
+        if let Some(closure) = context_menu {
+            if let Err(err) = window.window().remove_event_listener_with_callback(
+                "contextmenu",
+                closure.as_ref().unchecked_ref(),
+            ) {
+                // the listener is still attached, keep its closure alive
+                closure.forget();
+                return Err(err.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for KioskController {
+    fn drop(&mut self) {
+        let _ = self.exit();
+    }
+}
@@ -0,0 +1,414 @@
+//!
+//! Window snapping and tiling layouts across displays. Combines the display
+//! [`WorkArea`](crate::screen::WorkArea) information obtained from
+//! [`screen::screens()`](crate::screen::screens) with the window geometry
+//! to snap windows to halves, quarters or the center of a display and to move
+//! windows between displays.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::layout::{self, Snap};
+//!
+//! let win = nw_sys::window::get();
+//!
+//! // snap the window to the left half of its current display
+//! layout::snap(&win, Snap::LeftHalf)?;
+//!
+//! // move the window to the next display, keeping its relative position
+//! layout::move_to_next_screen(&win)?;
+//! ```
+//!
+
+use crate::error::Error;
+use crate::result::Result;
+use crate::screen::{self, Bounds, ScreenInfo, WorkArea};
+use crate::window::Window;
+
+/// Rectangle in screen coordinates (DIP).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// Area of the intersection between `self` and `other`.
+    pub fn intersection_area(&self, other: &Rect) -> f64 {
+        let width = (self.x + self.width).min(other.x + other.width) - self.x.max(other.x);
+        let height = (self.y + self.height).min(other.y + other.height) - self.y.max(other.y);
+        if width > 0.0 && height > 0.0 {
+            width * height
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns `true` if the point `x`,`y` is located within the rectangle.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+}
+
+impl From<&Bounds> for Rect {
+    fn from(bounds: &Bounds) -> Self {
+        Self {
+            x: bounds.x,
+            y: bounds.y,
+            width: bounds.width,
+            height: bounds.height,
+        }
+    }
+}
+
+impl From<&WorkArea> for Rect {
+    fn from(work_area: &WorkArea) -> Self {
+        Self {
+            x: work_area.x,
+            y: work_area.y,
+            width: work_area.width,
+            height: work_area.height,
+        }
+    }
+}
+
+/// Window snap position within the display work area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Snap {
+    LeftHalf,
+    RightHalf,
+    TopHalf,
+    BottomHalf,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    /// Center the window on the display keeping its size
+    /// (reduced to fit the work area if needed).
+    Center,
+    /// Fill the entire work area.
+    Fill,
+}
+
+impl Snap {
+    /// Compute the window rectangle for this snap position within
+    /// `work_area`. `window` is the current window rectangle, used
+    /// to preserve the window size for [`Snap::Center`].
+    pub fn rect(&self, work_area: &Rect, window: &Rect) -> Rect {
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = *work_area;
+        let half_width = (width / 2.0).floor();
+        let half_height = (height / 2.0).floor();
+
+        let (x, y, width, height) = match self {
+            Snap::LeftHalf => (x, y, half_width, height),
+            Snap::RightHalf => (x + half_width, y, width - half_width, height),
+            Snap::TopHalf => (x, y, width, half_height),
+            Snap::BottomHalf => (x, y + half_height, width, height - half_height),
+            Snap::TopLeft => (x, y, half_width, half_height),
+            Snap::TopRight => (x + half_width, y, width - half_width, half_height),
+            Snap::BottomLeft => (x, y + half_height, half_width, height - half_height),
+            Snap::BottomRight => (
+                x + half_width,
+                y + half_height,
+                width - half_width,
+                height - half_height,
+            ),
+            Snap::Center => {
+                let w = window.width.min(width);
+                let h = window.height.min(height);
+                (
+                    x + ((width - w) / 2.0).floor(),
+                    y + ((height - h) / 2.0).floor(),
+                    w,
+                    h,
+                )
+            }
+            Snap::Fill => (x, y, width, height),
+        };
+
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// Get the current window rectangle (including the window frame).
+pub fn window_rect(window: &Window) -> Rect {
+    Rect {
+        x: window.x() as f64,
+        y: window.y() as f64,
+        width: window.width() as f64,
+        height: window.height() as f64,
+    }
+}
+
+/// Apply the rectangle `rect` to the `window`.
+pub fn set_window_rect(window: &Window, rect: &Rect) {
+    // `set_x()`/`set_y()` are used as display coordinates can be negative
+    window.set_x(rect.x.round() as i32);
+    window.set_y(rect.y.round() as i32);
+    window.resize_to(
+        rect.width.round().max(0.0) as u32,
+        rect.height.round().max(0.0) as u32,
+    );
+}
+
+/// Get the list of displays, initializing [`nw_sys::screen`](crate::screen) if needed.
+fn screens() -> Result<Vec<ScreenInfo>> {
+    let screens = screen::screens()?;
+    if screens.is_empty() {
+        return Err(Error::String("layout: no displays found".to_string()));
+    }
+    Ok(screens)
+}
+
+/// Find the display that contains the largest part of `rect`. If `rect`
+/// does not intersect any display, the display closest to the center of
+/// `rect` is returned.
+pub fn screen_for_rect(rect: &Rect, screens: &[ScreenInfo]) -> Option<usize> {
+    let (index, area) = screens
+        .iter()
+        .map(|screen| rect.intersection_area(&(&screen.bounds).into()))
+        .enumerate()
+        .fold((None, 0.0), |(best, best_area), (index, area)| {
+            if area > best_area {
+                (Some(index), area)
+            } else {
+                (best, best_area)
+            }
+        });
+
+    if area > 0.0 {
+        return index;
+    }
+
+    let cx = rect.x + rect.width / 2.0;
+    let cy = rect.y + rect.height / 2.0;
+    screens
+        .iter()
+        .map(|screen| {
+            let bounds = Rect::from(&screen.bounds);
+            let dx = cx - (bounds.x + bounds.width / 2.0);
+            let dy = cy - (bounds.y + bounds.height / 2.0);
+            dx * dx + dy * dy
+        })
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
+
+/// Get the display the `window` is located on, determined from
+/// the window bounds.
+pub fn screen_for_window(window: &Window) -> Result<ScreenInfo> {
+    let screens = screens()?;
+    let index = screen_for_rect(&window_rect(window), &screens)
+        .ok_or_else(|| Error::String("layout: unable to locate window display".to_string()))?;
+    Ok(screens[index].clone())
+}
+
+/// Snap the `window` to the `snap` position on the display it is currently located on.
+pub fn snap(window: &Window, snap: Snap) -> Result<()> {
+    let screen = screen_for_window(window)?;
+    snap_to_screen(window, snap, &screen);
+    Ok(())
+}
+
+/// Snap the `window` to the `snap` position on the given `screen`.
+pub fn snap_to_screen(window: &Window, snap: Snap, screen: &ScreenInfo) {
+    let rect = snap.rect(&(&screen.work_area).into(), &window_rect(window));
+    set_window_rect(window, &rect);
+}
+
+/// Compute the rectangle of a window moved from the `from` display to
+/// the `to` display. The window position relative to the work area is
+/// preserved and the window size is scaled by the ratio of display scale
+/// factors, so that the window covers the same number of device pixels
+/// (its physical dimensions change if the displays have different pixel
+/// densities), and reduced to fit the target work area if needed.
+pub fn translate_rect(rect: &Rect, from: &ScreenInfo, to: &ScreenInfo) -> Rect {
+    let source = Rect::from(&from.work_area);
+    let target = Rect::from(&to.work_area);
+
+    let scale = if to.scale_factor > 0.0 {
+        from.scale_factor / to.scale_factor
+    } else {
+        1.0
+    };
+    let width = (rect.width * scale).min(target.width);
+    let height = (rect.height * scale).min(target.height);
+
+    // relative position of the window within the free space of the work area
+    let relative = |offset: f64, free: f64| {
+        if free > 0.0 {
+            (offset / free).clamp(0.0, 1.0)
+        } else {
+            0.5
+        }
+    };
+    let rx = relative(rect.x - source.x, source.width - rect.width);
+    let ry = relative(rect.y - source.y, source.height - rect.height);
+
+    Rect {
+        x: target.x + ((target.width - width) * rx).round(),
+        y: target.y + ((target.height - height) * ry).round(),
+        width,
+        height,
+    }
+}
+
+/// Move the `window` to the next display, preserving its relative
+/// position and size in device pixels (see [`translate_rect`]).
+/// Does nothing if only a single display is present.
+pub fn move_to_next_screen(window: &Window) -> Result<()> {
+    let screens = screens()?;
+    let rect = window_rect(window);
+    let index = screen_for_rect(&rect, &screens)
+        .ok_or_else(|| Error::String("layout: unable to locate window display".to_string()))?;
+    let next = (index + 1) % screens.len();
+    if next != index {
+        let rect = translate_rect(&rect, &screens[index], &screens[next]);
+        set_window_rect(window, &rect);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Display with a 40 DIP taskbar at the bottom
+    fn screen(scale_factor: f64, x: f64, y: f64, width: f64, height: f64) -> ScreenInfo {
+        ScreenInfo {
+            id: 0,
+            scale_factor,
+            is_built_in: false,
+            rotation: None,
+            touch_support: None,
+            bounds: Bounds {
+                x,
+                y,
+                width,
+                height,
+            },
+            work_area: WorkArea {
+                x,
+                y,
+                width,
+                height: height - 40.0,
+            },
+        }
+    }
+
+    #[test]
+    fn snap_to_edges() {
+        let work_area = rect(-1921.0, 0.0, 1921.0, 1041.0);
+        let window = rect(-1500.0, 100.0, 800.0, 600.0);
+
+        assert_eq!(
+            Snap::LeftHalf.rect(&work_area, &window),
+            rect(-1921.0, 0.0, 960.0, 1041.0)
+        );
+        // odd sizes: the right/bottom halves take the remaining pixel
+        assert_eq!(
+            Snap::RightHalf.rect(&work_area, &window),
+            rect(-961.0, 0.0, 961.0, 1041.0)
+        );
+        assert_eq!(
+            Snap::BottomRight.rect(&work_area, &window),
+            rect(-961.0, 520.0, 961.0, 521.0)
+        );
+        assert_eq!(
+            Snap::TopLeft.rect(&work_area, &window),
+            rect(-1921.0, 0.0, 960.0, 520.0)
+        );
+        assert_eq!(Snap::Fill.rect(&work_area, &window), work_area);
+    }
+
+    #[test]
+    fn snap_center_fits_work_area() {
+        let work_area = rect(0.0, 0.0, 1920.0, 1040.0);
+        assert_eq!(
+            Snap::Center.rect(&work_area, &rect(10.0, 10.0, 800.0, 600.0)),
+            rect(560.0, 220.0, 800.0, 600.0)
+        );
+        assert_eq!(
+            Snap::Center.rect(&work_area, &rect(10.0, 10.0, 2400.0, 600.0)),
+            rect(0.0, 220.0, 1920.0, 600.0)
+        );
+    }
+
+    #[test]
+    fn translate_to_higher_scale_factor() {
+        let from = screen(1.0, 0.0, 0.0, 1920.0, 1080.0);
+        let to = screen(2.0, 1920.0, 0.0, 1440.0, 900.0);
+
+        // top-left corner stays in the top-left corner, same device pixel size
+        assert_eq!(
+            translate_rect(&rect(0.0, 0.0, 800.0, 600.0), &from, &to),
+            rect(1920.0, 0.0, 400.0, 300.0)
+        );
+        // window snapped to the bottom-right edge stays on that edge
+        assert_eq!(
+            translate_rect(&rect(1120.0, 440.0, 800.0, 600.0), &from, &to),
+            rect(2960.0, 560.0, 400.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn translate_to_lower_scale_factor() {
+        let from = screen(2.0, 0.0, 0.0, 1440.0, 900.0);
+        let to = screen(1.0, -1920.0, -200.0, 1920.0, 1080.0);
+
+        // centered window stays centered
+        assert_eq!(
+            translate_rect(&rect(320.0, 230.0, 800.0, 400.0), &from, &to),
+            rect(-1760.0, -80.0, 1600.0, 800.0)
+        );
+        // size is reduced to fit the target work area
+        assert_eq!(
+            translate_rect(&rect(0.0, 0.0, 1440.0, 860.0), &from, &to),
+            rect(-1920.0, -200.0, 1920.0, 1040.0)
+        );
+    }
+
+    #[test]
+    fn screen_for_rect_largest_intersection() {
+        let screens = [
+            screen(1.0, 0.0, 0.0, 1920.0, 1080.0),
+            screen(2.0, 1920.0, 0.0, 1440.0, 900.0),
+        ];
+        assert_eq!(
+            screen_for_rect(&rect(100.0, 100.0, 800.0, 600.0), &screens),
+            Some(0)
+        );
+        // straddling both displays, mostly on the second one
+        assert_eq!(
+            screen_for_rect(&rect(1800.0, 100.0, 800.0, 600.0), &screens),
+            Some(1)
+        );
+        // off-screen, closest to the second display
+        assert_eq!(
+            screen_for_rect(&rect(4000.0, 100.0, 800.0, 600.0), &screens),
+            Some(1)
+        );
+        assert_eq!(screen_for_rect(&rect(0.0, 0.0, 10.0, 10.0), &[]), None);
+    }
+}
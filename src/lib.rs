@@ -28,6 +28,7 @@
 //! ---
 //!
 
+pub mod convert;
 pub mod error;
 pub mod options;
 pub mod result;
@@ -41,10 +42,20 @@ pub mod global;
 
 pub mod app;
 
+pub mod capture_sources;
+#[doc(inline)]
+pub use capture_sources::CaptureSourceList;
+
 pub mod clipboard;
 #[doc(inline)]
 pub use clipboard::Clipboard;
 
+pub mod kiosk;
+#[doc(inline)]
+pub use kiosk::KioskController;
+
+pub mod layout;
+
 pub mod menu;
 #[doc(inline)]
 pub use menu::Menu;
@@ -61,10 +72,26 @@ pub mod shortcut;
 #[doc(inline)]
 pub use shortcut::Shortcut;
 
+pub mod taskbar;
+#[doc(inline)]
+pub use taskbar::TaskbarProgress;
+
+pub mod title_bar;
+#[doc(inline)]
+pub use title_bar::TitleBar;
+
 pub mod tray;
 #[doc(inline)]
 pub use tray::Tray;
 
+pub mod tray_app;
+#[doc(inline)]
+pub use tray_app::TrayApp;
+
+pub mod tray_status;
+#[doc(inline)]
+pub use tray_status::TrayStatus;
+
 pub mod window;
 #[doc(inline)]
 pub use window::Window;
@@ -72,6 +99,6 @@ pub use window::Window;
 pub mod prelude;
 pub mod utils;
 
-mod chrome;
+pub mod chrome;
 #[doc(inline)]
 pub use chrome::notifications;
@@ -233,6 +233,12 @@ impl Options {
         self.set("label", JsValue::from(label))
     }
 
+    /// Label for normal item or checkbox, using the localized message `key`
+    /// with `substitutions` (see [`i18n::message`](crate::chrome::i18n::message)).
+    pub fn localized_label(self, key: &str, substitutions: &[&str]) -> Self {
+        self.label(&crate::chrome::i18n::message(key, substitutions))
+    }
+
     /// Icon for normal item or checkbox
     ///
     /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/MenuItem/#new-menuitemoption)
@@ -247,6 +253,12 @@ impl Options {
         self.set("tooltip", JsValue::from(tooltip))
     }
 
+    /// Tooltip for normal item or checkbox, using the localized message `key`
+    /// with `substitutions` (see [`i18n::message`](crate::chrome::i18n::message)).
+    pub fn localized_tooltip(self, key: &str, substitutions: &[&str]) -> Self {
+        self.tooltip(&crate::chrome::i18n::message(key, substitutions))
+    }
+
     /// The callback function when item is triggered by mouse click or keyboard shortcut
     ///
     /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/MenuItem/#new-menuitemoption)
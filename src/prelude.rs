@@ -2,6 +2,7 @@
 //! nw-sys prelude
 //!
 
+pub use crate::convert::FromJs;
 pub use crate::error;
 pub use crate::options::OptionsTrait;
 pub use crate::result;
@@ -16,5 +17,6 @@ pub use crate::Clipboard;
 pub use crate::Menu;
 pub use crate::MenuItem;
 pub use crate::Shortcut;
+pub use crate::TitleBar;
 pub use crate::Tray;
 pub use crate::Window;
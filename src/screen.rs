@@ -4,7 +4,8 @@
 //!
 //! # Synopsis
 //! ```
-//! // init must be called once during startup, before any function to nw.Screen can be called
+//! // nw.Screen is initialized on first use, init_once() can be
+//! // called during startup to initialize it ahead of time
 //! nw_sys::screen::init_once();
 //!
 //! let display_bounds_changed_callback = Callback::new(move |screen:JsValue|{
@@ -42,7 +43,8 @@
 //! ```
 //! use workflow_wasm::prelude::*;
 //!
-//! // init must be called once during startup, before any function to nw.Screen can be called
+//! // nw.Screen is initialized on first use, init_once() can be
+//! // called during startup to initialize it ahead of time
 //! nw_sys::screen::init_once();
 //!
 //! let display_bounds_changed_callback = callback!(move |screen:JsValue|{
@@ -75,12 +77,16 @@
 //!
 //! ```
 
-use js_sys::{Array, Function};
+use js_sys::{Array, Function, Object, Promise};
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
 //use workflow_log::log_info;
 //use crate::options::OptionsExt;
+use crate::error::Error;
 use crate::result::Result;
 use crate::utils;
+use std::cell::Cell;
 
 #[wasm_bindgen]
 extern "C" {
@@ -102,23 +108,43 @@ extern "C" {
     #[wasm_bindgen(js_namespace=["nw", "Screen"], js_name = chooseDesktopMedia)]
     fn choose_desktop_media_impl(sources: Array, callback: &Function);
 
+    #[wasm_bindgen(catch, js_namespace=["nw", "Screen"], js_name = chooseDesktopMedia)]
+    fn try_choose_desktop_media_impl(
+        sources: Array,
+        callback: &Function,
+    ) -> std::result::Result<(), JsValue>;
+
     #[wasm_bindgen(js_namespace=["nw", "Screen"], js_name = on)]
-    ///
-    ///
-    /// Interface for accessing display & monitor layout information. For usage example please refer to [nw_sys::screen](self)
-    ///
-    /// ### Events:
-    /// - displayBoundsChanged (screen)
-    /// - displayAdded (screen)
-    /// - displayRemoved (screen)
-    ///
-    ///
-    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#event-displayboundschangedscreen)
-    ///
-    pub fn on(event_name: &str, callback: &Function);
+    fn on_impl(event_name: &str, callback: &Function);
+
+    #[wasm_bindgen(js_namespace=["nw", "Screen"], js_name = removeListener)]
+    fn remove_listener_impl(event_name: &str, callback: &Function);
+}
+
+///
+///
+/// Interface for accessing display & monitor layout information. For usage example please refer to [nw_sys::screen](self)
+///
+/// ### Events:
+/// - displayBoundsChanged (screen)
+/// - displayAdded (screen)
+/// - displayRemoved (screen)
+///
+///
+/// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#event-displayboundschangedscreen)
+///
+pub fn on(event_name: &str, callback: &Function) {
+    init_once();
+    on_impl(event_name, callback);
+}
+
+/// Remove an event listener registered using [`on`]
+pub fn remove_listener(event_name: &str, callback: &Function) {
+    remove_listener_impl(event_name, callback);
 }
 
 pub mod desktop_capture_monitor {
+    use super::init_once;
     use js_sys::Function;
     use wasm_bindgen::prelude::*;
 
@@ -130,61 +156,22 @@ pub mod desktop_capture_monitor {
         type DCM;
 
         #[wasm_bindgen(getter, static_method_of=DCM, js_namespace=["nw", "Screen"], js_class=DesktopCaptureMonitor, js_name = started)]
-        /// Return Boolean of whether the DesktopCaptureMonitor is started.
-        ///
-        ///
-        /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screendesktopcapturemonitorstarted)
-        ///
-        pub fn started() -> bool;
+        fn started() -> bool;
 
         #[wasm_bindgen(js_namespace=["nw", "Screen", "DesktopCaptureMonitor"], js_name = start)]
-        /// The DesktopCaptureMonitor will start monitoring the system
-        /// and trigger the the events. The screen may flicker
-        /// if while DesktopCaptureMonitor is running.
-        ///
-        /// Example:
-        /// ```rust
-        /// nw::screen::desktop_capture_monitor::start(true, true);
-        /// ```
-        ///
-        /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screendesktopcapturemonitorstartshould_include_screens-should_include_windows)
-        ///
-        pub fn start(should_include_screens: bool, should_include_windows: bool);
+        fn start_impl(should_include_screens: bool, should_include_windows: bool);
 
         #[wasm_bindgen(js_namespace=["nw", "Screen", "DesktopCaptureMonitor"], js_name = stop)]
-        /// The `DesktopCaptureMonitor` will stop monitoring the system.
-        /// `DesktopCaptureMonitor` should be stopped after a stream is selected.
-        ///
-        ///
-        /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screendesktopcapturemonitorstop)
-        ///
-        pub fn stop();
+        fn stop_impl();
 
         #[wasm_bindgen(js_namespace=["nw", "Screen", "DesktopCaptureMonitor"], js_name = registerStream)]
-        /// Register and return a valid stream id which will be used into
-        /// chromeMediaSourceId in get_user_media constraints.
-        ///
-        /// See Synopsis for the usage.
-        ///
-        ///
-        /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screendesktopcapturemonitorregisterstreamid)
-        ///
-        pub fn register_stream(id: &str) -> String;
+        fn register_stream_impl(id: &str) -> String;
 
         #[wasm_bindgen(static_method_of=DCM, js_namespace=["nw", "Screen"], js_class=DesktopCaptureMonitor, js_name = on)]
-        /// Add event listener
-        ///
-        /// ### Events:
-        /// - added (id, name, order, type, primary)
-        /// - removed (order)
-        /// - orderchanged (id, new_order, old_order)
-        /// - namechanged (id, name)
-        /// - thumbnailchanged (id, thumbnail)
-        ///
-        ///
-        /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#event-added-id-name-order-type-primary)
-        ///
-        pub fn on(event_name: &str, callback: &Function);
+        fn on(event_name: &str, callback: &Function);
+
+        #[wasm_bindgen(static_method_of=DCM, js_namespace=["nw", "Screen"], js_class=DesktopCaptureMonitor, js_name = removeListener)]
+        fn remove_listener_impl(event_name: &str, callback: &Function);
     }
 
     /// Return Boolean of whether the DesktopCaptureMonitor is started.
@@ -193,9 +180,50 @@ pub mod desktop_capture_monitor {
     /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screendesktopcapturemonitorstarted)
     ///
     pub fn started() -> bool {
+        init_once();
         DCM::started()
     }
 
+    /// The DesktopCaptureMonitor will start monitoring the system
+    /// and trigger the the events. The screen may flicker
+    /// if while DesktopCaptureMonitor is running.
+    ///
+    /// Example:
+    /// ```rust
+    /// nw::screen::desktop_capture_monitor::start(true, true);
+    /// ```
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screendesktopcapturemonitorstartshould_include_screens-should_include_windows)
+    ///
+    pub fn start(should_include_screens: bool, should_include_windows: bool) {
+        init_once();
+        start_impl(should_include_screens, should_include_windows)
+    }
+
+    /// The `DesktopCaptureMonitor` will stop monitoring the system.
+    /// `DesktopCaptureMonitor` should be stopped after a stream is selected.
+    ///
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screendesktopcapturemonitorstop)
+    ///
+    pub fn stop() {
+        init_once();
+        stop_impl()
+    }
+
+    /// Register and return a valid stream id which will be used into
+    /// chromeMediaSourceId in get_user_media constraints.
+    ///
+    /// See Synopsis for the usage.
+    ///
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screendesktopcapturemonitorregisterstreamid)
+    ///
+    pub fn register_stream(id: &str) -> String {
+        init_once();
+        register_stream_impl(id)
+    }
+
     /// Add event listener
     ///
     /// ### Events:
@@ -209,23 +237,37 @@ pub mod desktop_capture_monitor {
     /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#event-added-id-name-order-type-primary)
     ///
     pub fn on(event_name: &str, callback: &Function) {
+        init_once();
         DCM::on(event_name, callback)
     }
+
+    /// Remove an event listener registered using [`on`]
+    pub fn remove_listener(event_name: &str, callback: &Function) {
+        DCM::remove_listener_impl(event_name, callback)
+    }
 }
 
-static mut INIT: bool = false;
+thread_local! {
+    // tracked per wasm instance: a module instantiated in another
+    // window calls `nw.Screen.Init()` again on first use
+    static INITIALIZED: Cell<bool> = const { Cell::new(false) };
+}
 
-/// Return true is screen is initialized
+/// Return true if screen has been initialized by this wasm instance
 pub fn is_initialized() -> bool {
-    unsafe { INIT }
+    INITIALIZED.with(|initialized| initialized.get())
 }
 
-/// Call the screen::init() if screen is not initialized yet
+/// Call the screen::init() if screen is not initialized yet.
+///
+/// Screen functions call this automatically, so calling it
+/// explicitly is only needed to initialize `nw.Screen` ahead of time.
 pub fn init_once() {
-    if !is_initialized() {
-        unsafe { INIT = true };
-        init();
-    }
+    INITIALIZED.with(|initialized| {
+        if !initialized.replace(true) {
+            init();
+        }
+    });
 }
 
 /// Media source type
@@ -235,6 +277,30 @@ pub enum MediaSources {
     ScreenAndWindow,
 }
 
+impl MediaSources {
+    /// Build the picker source list, adding `audio` to let the user
+    /// share the system audio along with the selected source.
+    fn to_array(&self, audio: bool) -> Array {
+        let array = Array::new();
+        match self {
+            MediaSources::Screen => {
+                array.push(&JsValue::from("screen"));
+            }
+            MediaSources::Window => {
+                array.push(&JsValue::from("window"));
+            }
+            MediaSources::ScreenAndWindow => {
+                array.push(&JsValue::from("screen"));
+                array.push(&JsValue::from("window"));
+            }
+        };
+        if audio {
+            array.push(&JsValue::from("audio"));
+        }
+        array
+    }
+}
+
 /// Choose desktop media
 ///
 /// Screen sharing by selection; Currently only working in Windows and OSX
@@ -243,22 +309,114 @@ pub enum MediaSources {
 /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screenchoosedesktopmedia-sources-callback)
 ///
 pub fn choose_desktop_media(sources: MediaSources, callback: &Function) -> Result<()> {
-    let array = Array::new();
-    match sources {
-        MediaSources::Screen => {
-            array.push(&JsValue::from("screen"));
-        }
-        MediaSources::Window => {
-            array.push(&JsValue::from("window"));
-        }
-        MediaSources::ScreenAndWindow => {
-            array.push(&JsValue::from("screen"));
-            array.push(&JsValue::from("window"));
+    init_once();
+    choose_desktop_media_impl(sources.to_array(false), callback);
+    Ok(())
+}
+
+/// Run the desktop media picker and return the selected stream id.
+///
+/// NW.js invokes the picker callback with an empty stream id when the
+/// user dismisses the picker, which resolves to [`Error::Cancelled`].
+/// If the picker can not be opened, the error is returned immediately
+/// instead of waiting for a callback that never fires.
+async fn choose_desktop_stream_id(sources: &MediaSources, audio: bool) -> Result<String> {
+    init_once();
+    let sources = sources.to_array(audio);
+    let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+        let callback = Closure::once_into_js(move |stream_id: JsValue| {
+            let _ = resolve.call1(&JsValue::undefined(), &stream_id);
+        });
+        if let Err(err) = try_choose_desktop_media_impl(sources.clone(), callback.unchecked_ref()) {
+            let _ = reject.call1(&JsValue::undefined(), &err);
         }
-    };
+    });
 
-    choose_desktop_media_impl(array, callback);
-    Ok(())
+    match JsFuture::from(promise).await?.as_string() {
+        Some(stream_id) if !stream_id.is_empty() => Ok(stream_id),
+        _ => Err(Error::Cancelled),
+    }
+}
+
+/// Build the `getUserMedia` constraint for the `desktop` media source
+fn desktop_constraint(stream_id: &str) -> Result<Object> {
+    let mandatory = Object::new();
+    js_sys::Reflect::set(
+        &mandatory,
+        &JsValue::from("chromeMediaSource"),
+        &JsValue::from("desktop"),
+    )?;
+    js_sys::Reflect::set(
+        &mandatory,
+        &JsValue::from("chromeMediaSourceId"),
+        &JsValue::from(stream_id),
+    )?;
+
+    let constraint = Object::new();
+    js_sys::Reflect::set(&constraint, &JsValue::from("mandatory"), &mandatory)?;
+    Ok(constraint)
+}
+
+/// Run the desktop media picker and open the selected screen or window
+/// as a [`MediaStream`](web_sys::MediaStream) containing a video track.
+/// If `audio` is `true`, the picker offers to share the system audio and
+/// it is captured as well (this is only supported on Windows and when
+/// capturing a screen).
+///
+/// Returns [`Error::Cancelled`] if the user dismissed the picker (NW.js
+/// reports this with an empty stream id).
+async fn capture_desktop_impl(sources: MediaSources, audio: bool) -> Result<web_sys::MediaStream> {
+    let stream_id = choose_desktop_stream_id(&sources, audio).await?;
+
+    let constraints = web_sys::MediaStreamConstraints::new();
+    constraints.set_video(&desktop_constraint(&stream_id)?.into());
+    if audio {
+        constraints.set_audio(&desktop_constraint(&stream_id)?.into());
+    } else {
+        constraints.set_audio(&JsValue::FALSE);
+    }
+
+    let media_devices = utils::window().navigator().media_devices()?;
+    let stream = JsFuture::from(media_devices.get_user_media_with_constraints(&constraints)?)
+        .await
+        .map_err(Error::exception)?;
+    Ok(stream.unchecked_into())
+}
+
+/// Run the desktop media picker and open the selected screen or window
+/// as a video [`MediaStream`](web_sys::MediaStream).
+///
+/// The future resolves once the picker is closed and returns
+/// [`Error::Cancelled`] if the user dismissed the picker.
+///
+/// ```rust,ignore
+/// use nw_sys::screen::{self, MediaSources};
+///
+/// match screen::capture_desktop(MediaSources::ScreenAndWindow).await {
+///     Ok(stream) => video_element.set_src_object(Some(&stream)),
+///     Err(nw_sys::error::Error::Cancelled) => {}
+///     Err(err) => log_error!("unable to capture desktop: {err}"),
+/// }
+/// ```
+///
+/// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screenchoosedesktopmedia-sources-callback)
+///
+pub async fn capture_desktop(sources: MediaSources) -> Result<web_sys::MediaStream> {
+    capture_desktop_impl(sources, false).await
+}
+
+/// Run the desktop media picker and open the selected screen or window
+/// as a [`MediaStream`](web_sys::MediaStream) containing a video track and
+/// the system audio track (system audio capture is only supported on Windows
+/// and when capturing a screen).
+///
+/// The future resolves once the picker is closed and returns
+/// [`Error::Cancelled`] if the user dismissed the picker.
+///
+/// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screenchoosedesktopmedia-sources-callback)
+///
+pub async fn capture_desktop_with_audio(sources: MediaSources) -> Result<web_sys::MediaStream> {
+    capture_desktop_impl(sources, true).await
 }
 
 /// Get the array of screen (number of screen connected to the computer)
@@ -266,6 +424,7 @@ pub fn choose_desktop_media(sources: MediaSources, callback: &Function) -> Resul
 /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Screen/#screenscreens)
 ///
 pub fn screens() -> Result<Vec<ScreenInfo>> {
+    init_once();
     let mut result: Vec<ScreenInfo> = Vec::new();
     let array = ScreenLocal::screens_impl();
     for index in 0..array.length() {
@@ -279,7 +438,7 @@ pub fn screens() -> Result<Vec<ScreenInfo>> {
 /// physical screen resolution, can be negative,
 /// not necessarily start from 0,
 /// depending on screen arrangement
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Bounds {
     pub x: f64,
     pub y: f64,
@@ -288,7 +447,7 @@ pub struct Bounds {
 }
 
 /// useable area within the screen bound
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct WorkArea {
     pub x: f64,
     pub y: f64,
@@ -296,14 +455,66 @@ pub struct WorkArea {
     pub height: f64,
 }
 
+/// Screen rotation (clockwise)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl Rotation {
+    /// Create from the rotation angle in degrees
+    pub fn from_degrees(degrees: u64) -> Option<Self> {
+        match degrees {
+            0 => Some(Self::Deg0),
+            90 => Some(Self::Deg90),
+            180 => Some(Self::Deg180),
+            270 => Some(Self::Deg270),
+            _ => None,
+        }
+    }
+
+    /// Rotation angle in degrees
+    pub fn degrees(&self) -> u64 {
+        match self {
+            Self::Deg0 => 0,
+            Self::Deg90 => 90,
+            Self::Deg180 => 180,
+            Self::Deg270 => 270,
+        }
+    }
+}
+
+/// Touch input support of the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TouchSupport {
+    Unknown,
+    Available,
+    Unavailable,
+}
+
+impl TouchSupport {
+    /// Create from the `touchSupport` value reported by `nw.Screen`
+    pub fn from_value(value: u64) -> Option<Self> {
+        match value {
+            0 => Some(Self::Unknown),
+            1 => Some(Self::Available),
+            2 => Some(Self::Unavailable),
+            _ => None,
+        }
+    }
+}
+
 /// Screen Info
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ScreenInfo {
     pub id: u64,
     pub scale_factor: f64,
     pub is_built_in: bool,
-    pub rotation: u64,
-    pub touch_support: u64,
+    pub rotation: Option<Rotation>,
+    pub touch_support: Option<TouchSupport>,
     pub bounds: Bounds,
     pub work_area: WorkArea,
 }
@@ -324,8 +535,12 @@ impl TryFrom<JsValue> for ScreenInfo {
         let id = utils::try_get_u64_from_prop(&jsv, "id")?;
         let scale_factor = utils::try_get_f64_from_prop(&jsv, "scaleFactor")?;
         let is_built_in = utils::try_get_bool_from_prop(&jsv, "isBuiltIn")?;
-        let rotation = utils::try_get_u64_from_prop(&jsv, "rotation")?;
-        let touch_support = utils::try_get_u64_from_prop(&jsv, "touchSupport")?;
+        let rotation = utils::try_get_u64_from_prop(&jsv, "rotation")
+            .ok()
+            .and_then(Rotation::from_degrees);
+        let touch_support = utils::try_get_u64_from_prop(&jsv, "touchSupport")
+            .ok()
+            .and_then(TouchSupport::from_value);
 
         let (x, y, width, height) = read_box(&jsv, "bounds")?;
         let bounds = Bounds {
@@ -46,7 +46,7 @@ extern "C" {
     /// Every time the user presses the registered shortcut,
     /// your app will receive an active event of the shortcut object.
     ///
-    #[wasm_bindgen(js_namespace=nw, js_name = Tray)]
+    #[wasm_bindgen(js_namespace=nw, js_name = Shortcut)]
     #[derive(Debug, Clone)]
     pub type Shortcut;
 
@@ -104,7 +104,7 @@ impl Options {
     /// - Alt
     /// - Shift
     /// - Command: Command modifier maps to Apple key (⌘) on Mac,
-    /// and maps to the Windows key on Windows and Linux.
+    ///   and maps to the Windows key on Windows and Linux.
     ///
     /// ### List of supported keys:
     ///
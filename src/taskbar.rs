@@ -0,0 +1,249 @@
+//!
+//! Taskbar / dock progress aggregation for multiple concurrent jobs.
+//!
+//! [`Window::set_progress_bar`] and [`Window::set_badge_label`] hold a single
+//! value per window. [`TaskbarProgress`] allows multiple background jobs to
+//! report their progress independently via [`TaskHandle`]s and computes the
+//! combined value displayed in the taskbar, updates the badge label with the
+//! number of active jobs and requests user attention when a job fails.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! let progress = nw_sys::TaskbarProgress::new(&nw_sys::window::get());
+//!
+//! let download = progress.task();
+//! let sync = progress.task();
+//!
+//! download.set_progress(0.25);
+//! sync.set_indeterminate();
+//!
+//! // completing or dropping a handle removes the job
+//! download.complete();
+//! sync.fail();
+//! ```
+//!
+
+use crate::window::Window;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// Progress bar value that removes the progress bar.
+const PROGRESS_NONE: f32 = -1.0;
+/// Progress bar value that displays an indeterminate progress bar.
+const PROGRESS_INDETERMINATE: f32 = 2.0;
+
+/// State of a single job tracked by [`TaskbarProgress`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TaskState {
+    /// Job progress in the range `0.0..=1.0`
+    Progress(f64),
+    /// Job progress is unknown
+    Indeterminate,
+    /// Job has failed
+    Error,
+}
+
+struct Inner {
+    window: Window,
+    next_id: u64,
+    tasks: BTreeMap<u64, TaskState>,
+}
+
+impl Inner {
+    fn update(&self) {
+        let (progress, active) = aggregate(self.tasks.values());
+        self.window.set_progress_bar(progress);
+        if active == 0 {
+            self.window.set_badge_label("");
+        } else {
+            self.window.set_badge_label(&active.to_string());
+        }
+    }
+}
+
+/// Compute the combined progress bar value and the number of active jobs.
+///
+/// The progress of jobs reporting [`TaskState::Progress`] is clamped to
+/// `0.0..=1.0` and averaged, non-finite values (e.g. `NaN`) are counted
+/// as indeterminate. If only indeterminate jobs are active, the progress
+/// bar is indeterminate.
+/// Failed jobs are not counted as active.
+fn aggregate<'a>(tasks: impl Iterator<Item = &'a TaskState>) -> (f32, usize) {
+    let mut sum = 0.0;
+    let mut determinate = 0;
+    let mut active = 0;
+    for task in tasks {
+        match task {
+            TaskState::Progress(progress) if progress.is_finite() => {
+                sum += progress.clamp(0.0, 1.0);
+                determinate += 1;
+                active += 1;
+            }
+            TaskState::Progress(_) | TaskState::Indeterminate => {
+                active += 1;
+            }
+            TaskState::Error => {}
+        }
+    }
+
+    let progress = if determinate > 0 {
+        (sum / determinate as f64) as f32
+    } else if active > 0 {
+        PROGRESS_INDETERMINATE
+    } else {
+        PROGRESS_NONE
+    };
+
+    (progress, active)
+}
+
+/// Aggregator combining the progress of multiple jobs into the
+/// taskbar progress bar and badge label of a [`Window`].
+#[derive(Clone)]
+pub struct TaskbarProgress {
+    inner: Rc<RefCell<Inner>>,
+}
+
+impl TaskbarProgress {
+    /// Create a progress aggregator for the given `window`.
+    pub fn new(window: &Window) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Inner {
+                window: window.clone(),
+                next_id: 0,
+                tasks: BTreeMap::new(),
+            })),
+        }
+    }
+
+    /// Register a new job. The job starts in the
+    /// [`TaskState::Indeterminate`] state.
+    pub fn task(&self) -> TaskHandle {
+        let mut inner = self.inner.borrow_mut();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.tasks.insert(id, TaskState::Indeterminate);
+        inner.update();
+
+        TaskHandle {
+            id,
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Number of active (not failed) jobs.
+    pub fn active(&self) -> usize {
+        aggregate(self.inner.borrow().tasks.values()).1
+    }
+
+    /// Remove all failed jobs.
+    pub fn clear_failed(&self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.tasks.retain(|_, state| *state != TaskState::Error);
+        inner.update();
+    }
+}
+
+/// Handle reporting the state of a single job to [`TaskbarProgress`].
+///
+/// The job is removed when the handle is dropped.
+pub struct TaskHandle {
+    id: u64,
+    inner: Rc<RefCell<Inner>>,
+}
+
+impl TaskHandle {
+    fn set_state(&self, state: TaskState) {
+        let mut inner = self.inner.borrow_mut();
+        let previous = inner.tasks.insert(self.id, state);
+        inner.update();
+
+        if state == TaskState::Error && previous != Some(TaskState::Error) {
+            inner.window.request_attention(true);
+        }
+    }
+
+    /// Set the job progress in the range `0.0..=1.0`. Values outside of
+    /// the range are clamped and non-finite values are shown as indeterminate.
+    pub fn set_progress(&self, progress: f64) {
+        self.set_state(TaskState::Progress(progress));
+    }
+
+    /// Mark the job progress as unknown.
+    pub fn set_indeterminate(&self) {
+        self.set_state(TaskState::Indeterminate);
+    }
+
+    /// Mark the job as failed and request user attention.
+    /// A failed job remains registered (and excluded from the combined
+    /// progress) until the handle is dropped or
+    /// [`TaskbarProgress::clear_failed`] is called.
+    pub fn fail(&self) {
+        self.set_state(TaskState::Error);
+    }
+
+    /// Get the current state of the job.
+    pub fn state(&self) -> Option<TaskState> {
+        self.inner.borrow().tasks.get(&self.id).copied()
+    }
+
+    /// Complete the job, removing it from the aggregator.
+    pub fn complete(self) {}
+}
+
+impl Drop for TaskHandle {
+    fn drop(&mut self) {
+        let mut inner = self.inner.borrow_mut();
+        if inner.tasks.remove(&self.id).is_some() {
+            inner.update();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregate_empty() {
+        assert_eq!(aggregate([].iter()), (PROGRESS_NONE, 0));
+        assert_eq!(aggregate([TaskState::Error].iter()), (PROGRESS_NONE, 0));
+    }
+
+    #[test]
+    fn aggregate_averages_progress() {
+        let tasks = [
+            TaskState::Progress(0.25),
+            TaskState::Progress(0.75),
+            TaskState::Indeterminate,
+            TaskState::Error,
+        ];
+        assert_eq!(aggregate(tasks.iter()), (0.5, 3));
+    }
+
+    #[test]
+    fn aggregate_indeterminate_only() {
+        let tasks = [TaskState::Indeterminate, TaskState::Error];
+        assert_eq!(aggregate(tasks.iter()), (PROGRESS_INDETERMINATE, 1));
+    }
+
+    #[test]
+    fn aggregate_clamps_out_of_range() {
+        let tasks = [TaskState::Progress(-3.0), TaskState::Progress(5.0)];
+        assert_eq!(aggregate(tasks.iter()), (0.5, 2));
+    }
+
+    #[test]
+    fn aggregate_ignores_non_finite() {
+        let tasks = [
+            TaskState::Progress(f64::NAN),
+            TaskState::Progress(f64::INFINITY),
+            TaskState::Progress(0.5),
+        ];
+        assert_eq!(aggregate(tasks.iter()), (0.5, 3));
+
+        let tasks = [TaskState::Progress(f64::NAN)];
+        assert_eq!(aggregate(tasks.iter()), (PROGRESS_INDETERMINATE, 1));
+    }
+}
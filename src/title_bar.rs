@@ -0,0 +1,252 @@
+//!
+//! Custom title bar support for frameless windows created using
+//! [`window::Options::frame(false)`](crate::window::Options::frame).
+//!
+//! [`TitleBar`] marks DOM elements as window drag regions
+//! (`-webkit-app-region: drag`), binds minimize, maximize, restore and close
+//! buttons to the corresponding [`Window`] functions and tracks the
+//! maximized state of the window.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! let title_bar = nw_sys::TitleBar::new(&nw_sys::window::get())
+//!     .drag_region("#title-bar")?
+//!     .no_drag_region("#title-bar button")?
+//!     .minimize_button("#minimize")?
+//!     .maximize_button("#maximize")?
+//!     .close_button("#close")?;
+//!
+//! title_bar.on_maximized_changed(|maximized| {
+//!     log_info!("window maximized: {maximized}");
+//! });
+//!
+//! // keep `title_bar` alive for as long as the buttons are in use
+//! ```
+//!
+
+use crate::error::Error;
+use crate::layout;
+use crate::result::Result;
+use crate::utils;
+use crate::window::Window;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+type MaximizedChangedHandler = Rc<RefCell<Option<Box<dyn FnMut(bool)>>>>;
+type WindowListener = (&'static str, Closure<dyn FnMut()>);
+type ElementListener = (HtmlElement, Closure<dyn FnMut()>);
+
+/// Window state tracked from the window `maximize`, `unmaximize`,
+/// `minimize` and `restore` events.
+#[derive(Default)]
+struct State {
+    maximized: Cell<bool>,
+    minimized: Cell<bool>,
+}
+
+/// Returns `true` if `window` covers the work area of its screen,
+/// allowing for a few DIP of window decorations.
+fn fills_work_area(window: &Window) -> bool {
+    const TOLERANCE: f64 = 8.0;
+    let Ok(screen) = layout::screen_for_window(window) else {
+        return false;
+    };
+    let rect = layout::window_rect(window);
+    let work_area = &screen.work_area;
+    rect.width + TOLERANCE >= work_area.width && rect.height + TOLERANCE >= work_area.height
+}
+
+/// Title bar helper for frameless windows.
+///
+/// Event listeners registered by the [`TitleBar`] are removed when it is dropped.
+pub struct TitleBar {
+    window: Window,
+    state: Rc<State>,
+    handler: MaximizedChangedHandler,
+    window_listeners: Vec<WindowListener>,
+    element_listeners: Vec<ElementListener>,
+}
+
+impl TitleBar {
+    /// Create a title bar helper for the given `window`.
+    ///
+    /// The initial maximized state is estimated by comparing the window
+    /// bounds with the work area of its screen, since NWJS does not
+    /// report whether a window is maximized.
+    pub fn new(window: &Window) -> Self {
+        let state = State::default();
+        state.maximized.set(fills_work_area(window));
+
+        let mut title_bar = Self {
+            window: window.clone(),
+            state: Rc::new(state),
+            handler: Rc::new(RefCell::new(None)),
+            window_listeners: Vec::new(),
+            element_listeners: Vec::new(),
+        };
+
+        title_bar.listen("maximize", |state| {
+            state.minimized.set(false);
+            Some(true)
+        });
+        title_bar.listen("unmaximize", |_| Some(false));
+        title_bar.listen("minimize", |state| {
+            state.minimized.set(true);
+            None
+        });
+        title_bar.listen("restore", |state| {
+            // restoring a minimized window returns it to its previous
+            // (possibly maximized) state, otherwise the window is unmaximized
+            if state.minimized.replace(false) {
+                None
+            } else {
+                Some(false)
+            }
+        });
+
+        title_bar
+    }
+
+    fn listen(&mut self, event: &'static str, update: fn(&State) -> Option<bool>) {
+        let state = self.state.clone();
+        let handler = self.handler.clone();
+        let closure = Closure::<dyn FnMut()>::new(move || {
+            if let Some(maximized) = update(&state) {
+                let previous = state.maximized.replace(maximized);
+                if previous != maximized {
+                    // release the borrow while the handler runs, so that it
+                    // can replace itself using `on_maximized_changed()`
+                    let callback = handler.borrow_mut().take();
+                    if let Some(mut callback) = callback {
+                        callback(maximized);
+                        handler.borrow_mut().get_or_insert(callback);
+                    }
+                }
+            }
+        });
+        self.window.on(event, closure.as_ref().unchecked_ref());
+        self.window_listeners.push((event, closure));
+    }
+
+    /// Returns `true` if the window is currently maximized.
+    pub fn is_maximized(&self) -> bool {
+        self.state.maximized.get()
+    }
+
+    /// Set a `callback` invoked each time the maximized state of
+    /// the window changes.
+    pub fn on_maximized_changed<F>(&self, callback: F)
+    where
+        F: FnMut(bool) + 'static,
+    {
+        *self.handler.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Mark elements matching `selector` as a window drag region
+    /// (`-webkit-app-region: drag`).
+    pub fn drag_region(self, selector: &str) -> Result<Self> {
+        set_app_region(selector, "drag")?;
+        Ok(self)
+    }
+
+    /// Mark elements matching `selector` as excluded from the window
+    /// drag region (`-webkit-app-region: no-drag`). Interactive elements
+    /// such as buttons placed within a drag region must be excluded,
+    /// otherwise they do not receive mouse events.
+    pub fn no_drag_region(self, selector: &str) -> Result<Self> {
+        set_app_region(selector, "no-drag")?;
+        Ok(self)
+    }
+
+    /// Minimize the window when an element matching `selector` is clicked.
+    pub fn minimize_button(self, selector: &str) -> Result<Self> {
+        self.button(selector, |window, _| window.minimize())
+    }
+
+    /// Maximize the window when an element matching `selector` is clicked.
+    /// If the window is already maximized, the window is unmaximized.
+    pub fn maximize_button(self, selector: &str) -> Result<Self> {
+        self.button(selector, |window, state| {
+            if state.maximized.get() {
+                window.unmaximize();
+            } else {
+                window.maximize();
+            }
+        })
+    }
+
+    /// Restore the window when an element matching `selector` is clicked.
+    /// If the window is maximized, the window is unmaximized.
+    pub fn restore_button(self, selector: &str) -> Result<Self> {
+        self.button(selector, |window, state| {
+            if state.maximized.get() {
+                window.unmaximize();
+            } else {
+                window.restore();
+            }
+        })
+    }
+
+    /// Close the window when an element matching `selector` is clicked.
+    /// The window `close` event is emitted as usual and can be used
+    /// to prevent the window from closing.
+    pub fn close_button(self, selector: &str) -> Result<Self> {
+        self.button(selector, |window, _| window.close())
+    }
+
+    fn button(mut self, selector: &str, action: fn(&Window, &State)) -> Result<Self> {
+        for element in query_selector_all(selector)? {
+            let window = self.window.clone();
+            let state = self.state.clone();
+            let closure = Closure::<dyn FnMut()>::new(move || action(&window, &state));
+            element.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())?;
+            self.element_listeners.push((element, closure));
+        }
+        Ok(self)
+    }
+}
+
+impl Drop for TitleBar {
+    fn drop(&mut self) {
+        for (event, closure) in self.window_listeners.iter() {
+            self.window
+                .remove_listener(event, closure.as_ref().unchecked_ref());
+        }
+        for (element, closure) in self.element_listeners.iter() {
+            let _ = element
+                .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+        }
+    }
+}
+
+fn query_selector_all(selector: &str) -> Result<Vec<HtmlElement>> {
+    let list = utils::document().query_selector_all(selector)?;
+    if list.length() == 0 {
+        return Err(Error::String(format!(
+            "TitleBar: no elements found matching '{selector}'"
+        )));
+    }
+
+    let mut elements = Vec::new();
+    for index in 0..list.length() {
+        if let Some(element) = list.get(index) {
+            let element = element.dyn_into::<HtmlElement>().map_err(|_| {
+                Error::String(format!(
+                    "TitleBar: element matching '{selector}' is not an HtmlElement"
+                ))
+            })?;
+            elements.push(element);
+        }
+    }
+    Ok(elements)
+}
+
+fn set_app_region(selector: &str, region: &str) -> Result<()> {
+    for element in query_selector_all(selector)? {
+        element.style().set_property("-webkit-app-region", region)?;
+    }
+    Ok(())
+}
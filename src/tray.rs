@@ -27,8 +27,11 @@
 
 use crate::menu::Menu;
 use crate::options::OptionsTrait;
+use crate::utils;
+use crate::window::Window;
 use js_sys::{Function, Object};
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 
 #[wasm_bindgen]
 extern "C" {
@@ -137,7 +140,7 @@ extern "C" {
     /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Tray/#trayiconsaretemplates-mac)
     pub fn icons_are_templates(this: &Tray) -> bool;
 
-    #[wasm_bindgen(method, setter, js_name=alticon)]
+    #[wasm_bindgen(method, setter, js_name=iconsAreTemplates)]
     /// (Mac) Set whether icon and alticon images are treated as “templates”
     /// (true by default).
     ///
@@ -183,6 +186,13 @@ extern "C" {
     /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Tray/#event-click)
     pub fn on(this: &Tray, event: &str, callback: &Function);
 
+    #[wasm_bindgen(method, js_name=removeListener)]
+    /// Remove the event listener `callback` previously registered
+    /// for the specified `event`
+    ///
+    /// ⧉ [Node.js Documentation](https://nodejs.org/api/events.html#emitterremovelistenereventname-listener)
+    pub fn remove_listener(this: &Tray, event: &str, callback: &Function);
+
     #[wasm_bindgen(extends = Object)]
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub type Options;
@@ -198,6 +208,12 @@ impl Options {
         self.set("title", JsValue::from(title))
     }
 
+    /// Set the title of the tray using the localized message `key`
+    /// with `substitutions` (see [`i18n::message`](crate::chrome::i18n::message)).
+    pub fn localized_title(self, key: &str, substitutions: &[&str]) -> Self {
+        self.title(&crate::chrome::i18n::message(key, substitutions))
+    }
+
     /// Set the tooltip of the tray. tooltip shows when you hover the Tray with mouse.
     ///
     /// Note: tooltip is showed on all three platforms.
@@ -208,6 +224,12 @@ impl Options {
         self.set("tooltip", JsValue::from(tooltip))
     }
 
+    /// Set the tooltip of the tray using the localized message `key`
+    /// with `substitutions` (see [`i18n::message`](crate::chrome::i18n::message)).
+    pub fn localized_tooltip(self, key: &str, substitutions: &[&str]) -> Self {
+        self.tooltip(&crate::chrome::i18n::message(key, substitutions))
+    }
+
     /// Set the icon of the tray, icon must a path to your icon file.
     /// It can be a relative path which points to an icon in your app,
     /// or an absolute path pointing to a file in user’s system.
@@ -257,3 +279,102 @@ impl Options {
         self.set("menu", JsValue::from(menu))
     }
 }
+
+/// Typed tray event
+///
+/// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Tray/#event-click)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrayEvent {
+    /// Emitted when user clicks the tray icon with left mouse button.
+    /// `x` and `y` are the screen coordinates of the click.
+    Click {
+        x: i32,
+        y: i32,
+        alt: bool,
+        ctrl: bool,
+        shift: bool,
+        meta: bool,
+    },
+}
+
+impl TrayEvent {
+    /// Screen coordinates of the event
+    pub fn position(&self) -> (i32, i32) {
+        match self {
+            TrayEvent::Click { x, y, .. } => (*x, *y),
+        }
+    }
+
+    /// Popup the `menu` at the event position. [`Menu::popup`] expects
+    /// coordinates relative to the `window`, so the event screen
+    /// coordinates are translated using the `window` position.
+    pub fn popup_menu(&self, menu: &Menu, window: &Window) {
+        let (x, y) = self.position();
+        menu.popup(x - window.x(), y - window.y());
+    }
+}
+
+impl TryFrom<JsValue> for TrayEvent {
+    type Error = crate::error::Error;
+    fn try_from(jsv: JsValue) -> std::result::Result<Self, Self::Error> {
+        let x = utils::try_get_f64_from_prop(&jsv, "x")? as i32;
+        let y = utils::try_get_f64_from_prop(&jsv, "y")? as i32;
+        // modifier keys are not reported on all platforms
+        let modifier = |prop| utils::try_get_bool_from_prop(&jsv, prop).unwrap_or(false);
+
+        Ok(TrayEvent::Click {
+            x,
+            y,
+            alt: modifier("altKey"),
+            ctrl: modifier("ctrlKey"),
+            shift: modifier("shiftKey"),
+            meta: modifier("metaKey"),
+        })
+    }
+}
+
+/// Tray event listener registered using [`Tray::on_click`].
+/// The listener is removed when dropped.
+pub struct TrayListener {
+    tray: Tray,
+    event: &'static str,
+    closure: Closure<dyn FnMut(JsValue)>,
+}
+
+impl Drop for TrayListener {
+    fn drop(&mut self) {
+        self.tray
+            .remove_listener(self.event, self.closure.as_ref().unchecked_ref());
+    }
+}
+
+impl Tray {
+    /// Register a `callback` receiving typed [`TrayEvent::Click`] events.
+    ///
+    /// ```rust,ignore
+    /// let win = nw_sys::window::get();
+    /// let listener = tray.on_click(move |event| {
+    ///     event.popup_menu(&quick_actions_menu, &win);
+    /// });
+    /// ```
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Tray/#event-click)
+    pub fn on_click<F>(&self, mut callback: F) -> TrayListener
+    where
+        F: FnMut(TrayEvent) + 'static,
+    {
+        let closure = Closure::<dyn FnMut(JsValue)>::new(move |jsv: JsValue| {
+            match TrayEvent::try_from(jsv) {
+                Ok(event) => callback(event),
+                Err(err) => crate::nw::error(&format!("unable to decode tray click event: {err}")),
+            }
+        });
+        self.on("click", closure.as_ref().unchecked_ref());
+
+        TrayListener {
+            tray: self.clone(),
+            event: "click",
+            closure,
+        }
+    }
+}
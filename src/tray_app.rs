@@ -0,0 +1,236 @@
+//!
+//! Tray-only (menubar) application mode. [`TrayApp`] combines a [`Tray`]
+//! icon with a frameless popover window that is positioned next to the
+//! tray click point, hidden when it loses focus and kept alive (hidden)
+//! instead of being closed, allowing applications to run without any
+//! visible windows.
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! let tray_options = nw_sys::tray::Options::new()
+//!     .icon("resources/icons/tray-icon@2x.png")
+//!     .tooltip("My Utility");
+//!
+//! // hide the main window and create the tray icon and popover
+//! let tray_app = nw_sys::TrayApp::new(&tray_options, "popover.html", 320, 420)?;
+//!
+//! // keep `tray_app` alive for the lifetime of the application
+//! ```
+//!
+
+use crate::layout::{self, Rect};
+use crate::options::OptionsTrait;
+use crate::result::Result;
+use crate::screen::{self, ScreenInfo};
+use crate::tray::{self, Tray, TrayEvent, TrayListener};
+use crate::window::{self, Window};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Tray clicks received within this period (in milliseconds) after the
+/// popover has been hidden due to a `blur` event are ignored, as clicking
+/// the tray icon while the popover is visible first causes the popover
+/// to lose focus.
+const BLUR_DEBOUNCE: f64 = 300.0;
+
+/// Distance (in DIP) between the popover and the tray click position.
+const MARGIN: f64 = 4.0;
+
+type WindowListener = (&'static str, Closure<dyn FnMut()>);
+
+#[derive(Default)]
+struct State {
+    popover: RefCell<Option<Window>>,
+    visible: Cell<bool>,
+    hidden_at: Cell<f64>,
+    listeners: RefCell<Vec<WindowListener>>,
+    /// Set when the [`TrayApp`] is dropped
+    dropped: Cell<bool>,
+}
+
+impl State {
+    fn hide(&self) {
+        if let Some(popover) = self.popover.borrow().as_ref() {
+            popover.hide();
+        }
+        self.visible.set(false);
+        self.hidden_at.set(js_sys::Date::now());
+    }
+
+    fn show_at(&self, x: i32, y: i32) -> Result<()> {
+        let popover = self.popover.borrow();
+        let Some(popover) = popover.as_ref() else {
+            return Ok(());
+        };
+
+        let screens = screen::screens()?;
+        let width = popover.width() as f64;
+        let height = popover.height() as f64;
+        if let Some((x, y)) = popover_position(x as f64, y as f64, width, height, &screens) {
+            popover.set_x(x);
+            popover.set_y(y);
+        }
+
+        popover.show();
+        popover.focus();
+        self.visible.set(true);
+        Ok(())
+    }
+}
+
+/// Compute the popover position next to the tray click point `x`,`y`.
+///
+/// The popover is horizontally centered on the click point and placed
+/// below it if the click occurred in the upper half of the display
+/// (menubar at the top of the screen), above it otherwise (taskbar at
+/// the bottom of the screen), constrained to the display work area.
+fn popover_position(
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    screens: &[ScreenInfo],
+) -> Option<(i32, i32)> {
+    let point = Rect {
+        x,
+        y,
+        width: 1.0,
+        height: 1.0,
+    };
+    let screen = &screens[layout::screen_for_rect(&point, screens)?];
+    let bounds = Rect::from(&screen.bounds);
+    let work_area = Rect::from(&screen.work_area);
+
+    let left = x - width / 2.0;
+    let top = if y < bounds.y + bounds.height / 2.0 {
+        y.max(work_area.y) + MARGIN
+    } else {
+        y.min(work_area.y + work_area.height) - height - MARGIN
+    };
+
+    let max_left = (work_area.x + work_area.width - width).max(work_area.x);
+    let max_top = (work_area.y + work_area.height - height).max(work_area.y);
+    Some((
+        left.clamp(work_area.x, max_left).round() as i32,
+        top.clamp(work_area.y, max_top).round() as i32,
+    ))
+}
+
+/// Tray-only application with a popover window.
+///
+/// Listeners are removed and the tray icon is removed when the [`TrayApp`] is dropped.
+pub struct TrayApp {
+    tray: Tray,
+    state: Rc<State>,
+    _click: TrayListener,
+}
+
+impl TrayApp {
+    /// Hide the current (main) window, create the tray icon using
+    /// `tray_options` and open `url` in a hidden popover window of the
+    /// given inner `width` and `height`. The popover is frameless, not
+    /// shown in the taskbar and always on top of other windows.
+    pub fn new(tray_options: &tray::Options, url: &str, width: u32, height: u32) -> Result<Self> {
+        // the main window is kept hidden (rather than closed) to keep
+        // the application running while no windows are visible
+        window::get().hide();
+
+        let state = Rc::new(State::default());
+        let options = window::Options::new()
+            .frame(false)
+            .show(false)
+            .show_in_taskbar(false)
+            .always_on_top(true)
+            .resizable(false)
+            .width(width)
+            .height(height);
+
+        let state_ = state.clone();
+        let callback = Closure::once_into_js(move |popover: Window| {
+            // the popover is opened asynchronously,
+            // close it if the app has been dropped meanwhile
+            if state_.dropped.get() {
+                popover.close_with_force();
+                return;
+            }
+
+            let mut listeners = Vec::new();
+
+            let state = state_.clone();
+            let blur = Closure::<dyn FnMut()>::new(move || state.hide());
+            popover.on("blur", blur.as_ref().unchecked_ref());
+            listeners.push(("blur", blur));
+
+            // closing the popover hides it instead; listening to the
+            // `close` event prevents the window from being closed
+            let state = state_.clone();
+            let close = Closure::<dyn FnMut()>::new(move || state.hide());
+            popover.on("close", close.as_ref().unchecked_ref());
+            listeners.push(("close", close));
+
+            *state_.listeners.borrow_mut() = listeners;
+            *state_.popover.borrow_mut() = Some(popover);
+        });
+        window::open_with_options_and_callback(url, &options, callback.unchecked_ref());
+
+        let tray = Tray::new(tray_options);
+        let state_ = state.clone();
+        let click = tray.on_click(move |event: TrayEvent| {
+            if state_.visible.get() {
+                state_.hide();
+            } else if js_sys::Date::now() - state_.hidden_at.get() > BLUR_DEBOUNCE {
+                let (x, y) = event.position();
+                if let Err(err) = state_.show_at(x, y) {
+                    crate::nw::error(&format!("TrayApp: unable to show popover: {err}"));
+                }
+            }
+        });
+
+        Ok(Self {
+            tray,
+            state,
+            _click: click,
+        })
+    }
+
+    /// Get the tray icon
+    pub fn tray(&self) -> &Tray {
+        &self.tray
+    }
+
+    /// Get the popover window. Returns `None` if the popover
+    /// window has not been opened yet.
+    pub fn popover(&self) -> Option<Window> {
+        self.state.popover.borrow().clone()
+    }
+
+    /// Returns `true` if the popover is currently visible.
+    pub fn is_visible(&self) -> bool {
+        self.state.visible.get()
+    }
+
+    /// Show the popover next to the screen position `x`,`y`.
+    pub fn show_at(&self, x: i32, y: i32) -> Result<()> {
+        self.state.show_at(x, y)
+    }
+
+    /// Hide the popover.
+    pub fn hide(&self) {
+        self.state.hide();
+    }
+}
+
+impl Drop for TrayApp {
+    fn drop(&mut self) {
+        self.state.dropped.set(true);
+        if let Some(popover) = self.state.popover.borrow_mut().take() {
+            for (event, closure) in self.state.listeners.borrow_mut().drain(..) {
+                popover.remove_listener(event, closure.as_ref().unchecked_ref());
+            }
+            popover.close_with_force();
+        }
+        self.tray.remove();
+    }
+}
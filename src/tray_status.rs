@@ -0,0 +1,162 @@
+//!
+//! Tray icon state machine. [`TrayStatus`] maps application states
+//! ([`TrayState`]) to tray icon sets and tooltips, animating multi-frame
+//! icon sets at a configurable rate via [`Tray::set_icon`].
+//!
+//! # Synopsis
+//! ```rust,ignore
+//! use nw_sys::tray_status::{TrayState, TrayStatus};
+//!
+//! let tray = nw_sys::Tray::new(&nw_sys::tray::Options::new().icon("icons/idle.png"));
+//!
+//! // the status controller takes ownership of the tray
+//! let mut status = TrayStatus::new(tray)
+//!     .state(TrayState::Idle, &["icons/idle.png"], "Up to date")
+//!     .state(
+//!         TrayState::Busy,
+//!         &["icons/sync-1.png", "icons/sync-2.png", "icons/sync-3.png"],
+//!         "Syncing...",
+//!     )
+//!     .state(TrayState::Error, &["icons/error.png"], "Sync failed")
+//!     .state(TrayState::Offline, &["icons/offline.png"], "Offline")
+//!     .interval(250);
+//!
+//! status.set_state(TrayState::Busy)?;
+//!
+//! // stop the animation and remove the tray icon
+//! status.remove();
+//! ```
+//!
+
+use crate::error::Error;
+use crate::result::Result;
+use crate::tray::Tray;
+use crate::utils::Interval;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Default animation frame interval in milliseconds
+const DEFAULT_INTERVAL: u32 = 200;
+
+/// Application state displayed by [`TrayStatus`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrayState {
+    Idle,
+    Busy,
+    Error,
+    Offline,
+}
+
+/// Icon frames and tooltip associated with a [`TrayState`]
+#[derive(Debug, Clone)]
+struct StateIcons {
+    frames: Rc<Vec<String>>,
+    tooltip: String,
+}
+
+/// Controller displaying the application state in a [`Tray`] icon.
+///
+/// The controller owns the tray: the icon must only be removed through
+/// [`TrayStatus::remove`], which stops the animation before removing it.
+/// Removing a clone of the tray directly would leave the animation
+/// updating a removed tray. The animation is stopped when the
+/// [`TrayStatus`] is dropped.
+pub struct TrayStatus {
+    tray: Tray,
+    states: HashMap<TrayState, StateIcons>,
+    interval: u32,
+    current: Option<TrayState>,
+    animation: Option<Interval>,
+}
+
+impl TrayStatus {
+    /// Create a status controller taking ownership of the given `tray`.
+    pub fn new(tray: Tray) -> Self {
+        Self {
+            tray,
+            states: HashMap::new(),
+            interval: DEFAULT_INTERVAL,
+            current: None,
+            animation: None,
+        }
+    }
+
+    /// Associate icon `frames` and a `tooltip` with the given `state`.
+    /// If more than one frame is supplied, frames are animated
+    /// while the state is active.
+    pub fn state(mut self, state: TrayState, frames: &[&str], tooltip: &str) -> Self {
+        self.states.insert(
+            state,
+            StateIcons {
+                frames: Rc::new(frames.iter().map(|frame| frame.to_string()).collect()),
+                tooltip: tooltip.to_string(),
+            },
+        );
+        self
+    }
+
+    /// Animation frame interval in milliseconds (200 by default).
+    pub fn interval(mut self, interval: u32) -> Self {
+        self.interval = interval.max(1);
+        self
+    }
+
+    /// Get the tray displaying the state, for example to register
+    /// event listeners. Use [`TrayStatus::remove`] to remove the tray.
+    pub fn tray(&self) -> &Tray {
+        &self.tray
+    }
+
+    /// Get the currently displayed state.
+    pub fn current(&self) -> Option<TrayState> {
+        self.current
+    }
+
+    /// Display the given `state`, updating the tray icon and tooltip
+    /// and starting the icon animation if the state has multiple frames.
+    pub fn set_state(&mut self, state: TrayState) -> Result<()> {
+        if self.current == Some(state) {
+            return Ok(());
+        }
+
+        let icons = self.states.get(&state).cloned().ok_or_else(|| {
+            Error::String(format!("TrayStatus: no icons registered for {state:?}"))
+        })?;
+        let first = icons
+            .frames
+            .first()
+            .ok_or_else(|| Error::String(format!("TrayStatus: empty icon set for {state:?}")))?;
+
+        self.stop();
+        self.tray.set_icon(first);
+        self.tray.set_tooltip(&icons.tooltip);
+
+        if icons.frames.len() > 1 {
+            let tray = self.tray.clone();
+            let frames = icons.frames.clone();
+            let index = Cell::new(0);
+            self.animation = Some(Interval::new(self.interval, move || {
+                let next = (index.get() + 1) % frames.len();
+                index.set(next);
+                tray.set_icon(&frames[next]);
+            })?);
+        }
+
+        self.current = Some(state);
+        Ok(())
+    }
+
+    /// Stop the icon animation, leaving the current icon displayed.
+    /// The next call to [`TrayStatus::set_state`] re-applies the state.
+    pub fn stop(&mut self) {
+        self.animation.take();
+        self.current = None;
+    }
+
+    /// Stop the icon animation and remove the tray icon.
+    pub fn remove(mut self) {
+        self.stop();
+        self.tray.remove();
+    }
+}
@@ -3,6 +3,7 @@
 //!
 
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 use web_sys::{Document, HtmlElement, Window};
 
 /// Return Document element
@@ -58,8 +59,63 @@ pub fn try_get_bool_from_prop(jsv: &JsValue, prop: &str) -> Result<bool, JsValue
         })
 }
 
+/// Obtain a `String` value from the object property `prop`
+pub fn try_get_string_from_prop(jsv: &JsValue, prop: &str) -> Result<String, JsValue> {
+    js_sys::Reflect::get(jsv, &JsValue::from(prop))?
+        .as_string()
+        .ok_or_else(|| {
+            JsValue::from(format!(
+                "try_get_string(): property {} is missing or not a string",
+                prop
+            ))
+        })
+}
+
 /// Obtain a `JsValue` value from the object property `prop`
 pub fn try_get_js_value(this_jsv: &JsValue, prop: &str) -> Result<JsValue, JsValue> {
     let v = js_sys::Reflect::get(this_jsv, &JsValue::from(prop))?;
     Ok(v)
 }
+
+/// Repeating timer created using `setInterval()`.
+/// The interval is cleared when the [`Interval`] is dropped.
+pub(crate) struct Interval {
+    handle: i32,
+    _closure: Closure<dyn FnMut()>,
+}
+
+impl Interval {
+    /// Invoke `callback` every `timeout` milliseconds.
+    pub(crate) fn new<F>(timeout: u32, callback: F) -> Result<Self, JsValue>
+    where
+        F: FnMut() + 'static,
+    {
+        let closure = Closure::<dyn FnMut()>::new(callback);
+        let handle = window().set_interval_with_callback_and_timeout_and_arguments_0(
+            closure.as_ref().unchecked_ref(),
+            timeout as i32,
+        )?;
+        Ok(Self {
+            handle,
+            _closure: closure,
+        })
+    }
+}
+
+impl Drop for Interval {
+    fn drop(&mut self) {
+        window().clear_interval_with_handle(self.handle);
+    }
+}
+
+/// Invoke `callback` once after `timeout` milliseconds using `setTimeout()`.
+pub(crate) fn set_timeout<F>(timeout: u32, callback: F) -> Result<i32, JsValue>
+where
+    F: FnOnce() + 'static,
+{
+    let closure = Closure::once_into_js(callback);
+    window().set_timeout_with_callback_and_timeout_and_arguments_0(
+        closure.unchecked_ref(),
+        timeout as i32,
+    )
+}
@@ -50,8 +50,11 @@
 //!
 //! ```
 
+use crate::convert::FromJs;
+use crate::error::Error;
 use crate::menu::Menu;
 use crate::options::OptionsTrait;
+use crate::result::Result;
 use cfg_if::cfg_if;
 use js_sys::{ArrayBuffer, Function, Object, Promise};
 use wasm_bindgen::prelude::*;
@@ -59,7 +62,6 @@ use web_sys::HtmlIFrameElement;
 
 #[wasm_bindgen]
 extern "C" {
-    // TODO: win.cookies.*
     ///
     /// Interface for managing application windows. For usage example please refer to [nw_sys::window](self)
     ///
@@ -207,7 +209,13 @@ extern "C" {
     ///
     pub fn set_zoom_level(this: &Window, zoom: i16);
 
-    //TODO: Cookies
+    #[wasm_bindgen(method, getter, js_name = cookies)]
+    /// Get the cookies API of the window, which has the same interface
+    /// as `chrome.cookies` (see [`Cookies`](crate::chrome::cookies::Cookies)).
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Window/#wincookies)
+    ///
+    pub fn cookies(this: &Window) -> crate::chrome::cookies::Cookies;
 
     #[wasm_bindgen(method, js_name = moveTo)]
     /// Moves a window’s left and top edge to the specified coordinates.
@@ -703,6 +711,28 @@ extern "C" {
         module_path: &str,
     );
 
+    #[wasm_bindgen(method, catch, js_name=eval)]
+    fn try_eval_impl(
+        this: &Window,
+        iframe: JsValue,
+        script: &str,
+    ) -> std::result::Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name=evalNWBin)]
+    fn try_eval_nw_bin_impl(
+        this: &Window,
+        iframe: JsValue,
+        script: JsValue,
+    ) -> std::result::Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name=evalNWBinModule)]
+    fn try_eval_nw_bin_module_impl(
+        this: &Window,
+        iframe: JsValue,
+        script: JsValue,
+        module_path: &str,
+    ) -> std::result::Result<JsValue, JsValue>;
+
     #[wasm_bindgen(method, js_name=removeAllListeners)]
     /// Removes all listeners
     ///
@@ -724,6 +754,14 @@ extern "C" {
     ///
     pub fn on(this: &Window, event_name: &str, callback: &Function);
 
+    #[wasm_bindgen(method, js_name=removeListener)]
+    /// Remove the event listener `callback` previously registered
+    /// for the specified `event_name`
+    ///
+    /// ⧉ [Node.js Documentation](https://nodejs.org/api/events.html#emitterremovelistenereventname-listener)
+    ///
+    pub fn remove_listener(this: &Window, event_name: &str, callback: &Function);
+
 }
 
 #[wasm_bindgen]
@@ -805,6 +843,46 @@ pub enum NWBinary {
     //Buffer(Buffer)
 }
 
+impl NWBinary {
+    /// Create [`NWBinary::Path`] pointing to a compiled binary located
+    /// at `relative_path` within the application directory
+    /// ([`app::start_path()`](crate::app::start_path)). Absolute paths
+    /// and paths containing `..` components are rejected.
+    ///
+    pub fn from_start_path(relative_path: &str) -> Result<Self> {
+        let relative_path = relative_path.trim_start_matches("./");
+        if relative_path.is_empty()
+            || relative_path.starts_with('/')
+            || relative_path.starts_with('\\')
+            || relative_path.contains(':')
+            || relative_path
+                .split(['/', '\\'])
+                .any(|component| component == "..")
+        {
+            return Err(Error::String(format!(
+                "NWBinary::from_start_path(): '{relative_path}' is not a path within the start path"
+            )));
+        }
+
+        let start_path = crate::app::start_path();
+        let start_path = start_path.trim_end_matches(['/', '\\']);
+        Ok(NWBinary::Path(format!("{start_path}/{relative_path}")))
+    }
+}
+
+impl From<NWBinary> for JsValue {
+    fn from(script: NWBinary) -> Self {
+        match script {
+            NWBinary::Path(path) => JsValue::from(path),
+            NWBinary::ArrayBuffer(buffer) => JsValue::from(buffer),
+        }
+    }
+}
+
+fn iframe_or_null(iframe: Option<HtmlIFrameElement>) -> JsValue {
+    iframe.map(JsValue::from).unwrap_or_else(JsValue::null)
+}
+
 impl Window {
     /// Set window’s menubar = null.
     ///
@@ -895,6 +973,93 @@ impl Window {
             self.eval_nw_bin_module_impl(JsValue::null(), script, module_path);
         }
     }
+
+    /// Execute a piece of JavaScript in the frame and decode the result
+    /// into `T`. JavaScript exceptions are returned as
+    /// [`Error::Exception`] containing the exception message and stack.
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Window/#winevalframe-script)
+    ///
+    pub fn try_eval<T: FromJs>(
+        &self,
+        iframe: Option<HtmlIFrameElement>,
+        script: &str,
+    ) -> Result<T> {
+        let result = self
+            .try_eval_impl(iframe_or_null(iframe), script)
+            .map_err(Error::exception)?;
+        T::from_js(result)
+    }
+
+    /// Execute a piece of JavaScript in the given `iframe` and decode
+    /// the result into `T`.
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Window/#winevalframe-script)
+    ///
+    pub fn try_eval_with_iframe<T: FromJs>(
+        &self,
+        iframe: &HtmlIFrameElement,
+        script: &str,
+    ) -> Result<T> {
+        self.try_eval(Some(iframe.clone()), script)
+    }
+
+    /// Load and execute the compiled binary in the frame and decode
+    /// the result into `T`. JavaScript exceptions are returned as
+    /// [`Error::Exception`].
+    ///
+    /// See [Protect JavaScript Source Code](https://docs.nwjs.io/en/latest/For%20Users/Advanced/Protect%20JavaScript%20Source%20Code/).
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Window/#winevalnwbinframe-path)
+    ///
+    pub fn try_eval_nw_bin<T: FromJs>(
+        &self,
+        iframe: Option<HtmlIFrameElement>,
+        script: NWBinary,
+    ) -> Result<T> {
+        let result = self
+            .try_eval_nw_bin_impl(iframe_or_null(iframe), script.into())
+            .map_err(Error::exception)?;
+        T::from_js(result)
+    }
+
+    /// Load and execute the compiled binary for Modules in the frame and
+    /// decode the result into `T`. JavaScript exceptions are returned as
+    /// [`Error::Exception`].
+    ///
+    /// The binary should be compiled with nwjc --nw-module.
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Window/#winevalnwbinmoduleframe-path-module_path)
+    ///
+    pub fn try_eval_nw_bin_module<T: FromJs>(
+        &self,
+        iframe: Option<HtmlIFrameElement>,
+        script: NWBinary,
+        module_path: &str,
+    ) -> Result<T> {
+        let result = self
+            .try_eval_nw_bin_module_impl(iframe_or_null(iframe), script.into(), module_path)
+            .map_err(Error::exception)?;
+        T::from_js(result)
+    }
+
+    /// Load and execute a compiled binary located at `relative_path`
+    /// within the application directory ([`app::start_path()`](crate::app::start_path)).
+    ///
+    /// ```rust,ignore
+    /// let win = nw_sys::window::get();
+    /// win.try_eval_nw_bin_from_start_path::<()>(None, "lib/app.bin")?;
+    /// ```
+    ///
+    /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Window/#winevalnwbinframe-path)
+    ///
+    pub fn try_eval_nw_bin_from_start_path<T: FromJs>(
+        &self,
+        iframe: Option<HtmlIFrameElement>,
+        relative_path: &str,
+    ) -> Result<T> {
+        self.try_eval_nw_bin(iframe, NWBinary::from_start_path(relative_path)?)
+    }
 }
 
 cfg_if! {
@@ -1063,7 +1228,6 @@ impl Options {
     ///   -webkit-app-region: no-drag;
     /// }
     /// ```
-
     ///
     /// ⧉ [NWJS Documentation](https://docs.nwjs.io/en/latest/References/Manifest%20Format/#frame)
     pub fn frame(self, frame: bool) -> Self {